@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datetimeparse::{parse_rfc3339_datetime, Builder};
+
+const INPUTS: [&str; 3] = [
+    "2023-09-17T09:08:58Z",
+    "2023-09-17T09:08:58.763Z",
+    "2023-09-17T09:08:58.763072Z",
+];
+
+fn general(s: &str) {
+    let mut parser = Builder::new_rfc3339().into_parser();
+    parser
+        .parse_precise_shifted_date_time(s.as_bytes())
+        .unwrap();
+    black_box(parser.build_precise_shifted_date_time().unwrap());
+}
+
+fn bench_rfc3339(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rfc3339_utc");
+    for input in INPUTS {
+        group.bench_with_input(format!("fast/{}", input.len()), input, |b, s| {
+            b.iter(|| parse_rfc3339_datetime(black_box(s)).unwrap())
+        });
+        group.bench_with_input(format!("general/{}", input.len()), input, |b, s| {
+            b.iter(|| general(black_box(s)))
+        });
+    }
+    group.finish();
+
+    // Inputs the fast path rejects pay for the attempt before falling back
+    c.bench_function("rfc3339_offset", |b| {
+        b.iter(|| parse_rfc3339_datetime(black_box("2023-09-17T09:08:58.763+02:00")).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_rfc3339);
+criterion_main!(benches);
@@ -34,13 +34,13 @@ pub extern "C" fn pdt_parse_rfc3339_datetime(
         return PDT_PARSE_ERROR;
     };
     unsafe {
-        (*out).year = dt.year.try_into().unwrap();
-        (*out).month = dt.month.try_into().unwrap();
-        (*out).day = dt.day.try_into().unwrap();
-        (*out).hour = dt.hour.try_into().unwrap();
-        (*out).minute = dt.minute.try_into().unwrap();
-        (*out).second = dt.second.try_into().unwrap();
-        (*out).nanosecond = dt.nanosecond.try_into().unwrap();
+        (*out).year = dt.year.into();
+        (*out).month = dt.month.into();
+        (*out).day = dt.day.into();
+        (*out).hour = dt.hour.into();
+        (*out).minute = dt.minute.into();
+        (*out).second = dt.second.into();
+        (*out).nanosecond = dt.nanosecond.into();
     }
     PDT_SUCCESS
 }
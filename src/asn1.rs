@@ -0,0 +1,390 @@
+//! ASN.1 `UTCTime` and `GeneralizedTime`
+//!
+//! X.509 certificates store their validity period in these types. `UTCTime`
+//! is `YYMMDDhhmmssZ`, where years 50 to 99 are 1950 to 1999 and years 00 to
+//! 49 are 2000 to 2049. `GeneralizedTime` is `YYYYMMDDhhmmss[.f]Z`.
+//!
+//! The DER profile only accepts the canonical encoding of X.690: seconds are
+//! always present, the time is in UTC with a `Z`, and a fraction uses `.`
+//! without trailing zeros. The BER profile also accepts a missing seconds or
+//! minutes component, an offset like `+0200` instead of `Z`, a `,` before the
+//! fraction and trailing zeros. In a BER `GeneralizedTime` the fraction
+//! belongs to the last component present, so it can also be a fraction of the
+//! hour or minute with up to nine digits: `2023091709.5Z` is 09:30. A
+//! `GeneralizedTime` in local time, without `Z` or an offset, can't be
+//! represented and is rejected.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::asn1::{self, Profile};
+//! let dt = asn1::parse_utc_time("230917090858Z", Profile::Der).unwrap();
+//! assert_eq!(dt.to_string(), "2023-09-17T09:08:58.0Z");
+//! let dt = asn1::parse_generalized_time("20230917110858,5+0200", Profile::Ber).unwrap();
+//! assert_eq!(asn1::format_generalized_time(&dt).unwrap(), "20230917090858.5Z");
+//! ```
+
+use crate::{
+    combined::{LocalDate, PreciseShiftedDateTime},
+    components::{self, Hour, Minute, Nanosecond, Second, SimpleYear, Timeshift, Year},
+    parse::{BuildError, Element, ParseContext},
+    parse_utils::{self, parse_n_digits, ParseError},
+    Error,
+};
+
+/// The encoding rules an ASN.1 time is checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// The Distinguished Encoding Rules, which allow one encoding per time
+    Der,
+    /// The Basic Encoding Rules, which allow several
+    Ber,
+}
+
+/// Parse a `UTCTime`
+pub fn parse_utc_time(inp: &str, profile: Profile) -> Result<PreciseShiftedDateTime, Error<'_>> {
+    parse(inp.as_bytes(), profile, false)
+}
+
+/// Parse a `GeneralizedTime`
+pub fn parse_generalized_time(
+    inp: &str,
+    profile: Profile,
+) -> Result<PreciseShiftedDateTime, Error<'_>> {
+    parse(inp.as_bytes(), profile, true)
+}
+
+fn parse(
+    data: &[u8],
+    profile: Profile,
+    generalized: bool,
+) -> Result<PreciseShiftedDateTime, Error<'_>> {
+    // Only the basic format has no separators, so check the digits up front
+    let year_digits = if generalized { 4 } else { 2 };
+    let digits = data.iter().take_while(|c| c.is_ascii_digit()).count();
+    let time_digits = digits.saturating_sub(year_digits);
+    let allowed = match profile {
+        Profile::Der => time_digits == 10,
+        // UTCTime always has minutes, GeneralizedTime can stop after the hour
+        Profile::Ber if generalized => matches!(time_digits, 6 | 8 | 10),
+        Profile::Ber => matches!(time_digits, 8 | 10),
+    };
+    if !allowed {
+        return Err(ParseError::Fail(&data[digits..]).into());
+    }
+
+    let mut parser = ParseContext::new_iso8601().into_parser::<SimpleYear>();
+    let rest = if generalized {
+        parser.parse_year(data)?
+    } else {
+        let (year, rest) = parse_n_digits(2, data)?;
+        let year = if year < 50 { year + 2000 } else { year + 1900 };
+        parser.push_element(Element::Year(
+            Year::new(year as i32).map_err(ParseError::from)?,
+        ));
+        rest
+    };
+    let rest = parser.parse_month(rest)?;
+    let rest = parser.parse_day(rest)?;
+    let rest = parser.parse_hour(rest)?;
+    let rest = match time_digits {
+        6 => rest,
+        _ => parser.parse_minute(rest)?,
+    };
+    let rest = match time_digits {
+        10 => parser.parse_second(rest)?,
+        _ => rest,
+    };
+
+    // A fraction belongs to the last component, which BER also allows to be
+    // the hour or the minute
+    let rest = match rest.first() {
+        Some(b'.' | b',') if generalized && (time_digits == 10 || profile == Profile::Ber) => {
+            if profile == Profile::Der && rest[0] == b',' {
+                return Err(ParseError::Fail(rest).into());
+            }
+            let fraction = &rest[1..];
+            let length = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+            // DER has no empty fraction and no trailing zeros
+            if length == 0 || (profile == Profile::Der && fraction[length - 1] == b'0') {
+                return Err(ParseError::Fail(rest).into());
+            }
+            match time_digits {
+                10 => parser.parse_fractional_seconds(fraction)?,
+                _ if length > 9 => return Err(ParseError::Fail(&fraction[9..]).into()),
+                _ => {
+                    // Nine digits of an hour or a minute are a whole number of nanoseconds
+                    let unit = if time_digits == 6 { 3600 } else { 60 };
+                    let nanoseconds = parse_utils::nanoseconds(&fraction[..length]) * unit;
+                    let seconds = nanoseconds / 1_000_000_000;
+                    if time_digits == 6 {
+                        parser.push_element(Element::Minute(
+                            Minute::new(seconds / 60).map_err(ParseError::from)?,
+                        ));
+                    }
+                    parser.push_element(Element::Second(
+                        Second::new(seconds % 60).map_err(ParseError::from)?,
+                    ));
+                    parser.push_element(Element::Nanosecond(
+                        Nanosecond::new(nanoseconds % 1_000_000_000).map_err(ParseError::from)?,
+                    ));
+                    &fraction[length..]
+                }
+            }
+        }
+        _ => {
+            if time_digits == 6 {
+                parser.push_element(Element::Minute(Minute::new(0).map_err(ParseError::from)?));
+            }
+            if time_digits != 10 {
+                parser.push_element(Element::Second(Second::new(0).map_err(ParseError::from)?));
+            }
+            parser.push_element(Element::Nanosecond(
+                Nanosecond::new(0).map_err(ParseError::from)?,
+            ));
+            rest
+        }
+    };
+
+    let rest = match (profile, rest.first()) {
+        (Profile::Der, Some(b'Z')) | (Profile::Ber, Some(b'Z' | b'+' | b'-')) => {
+            let offset = &rest[1.min(rest.len())..];
+            let offset_digits = offset.iter().take_while(|c| c.is_ascii_digit()).count();
+            match (rest[0], offset_digits) {
+                (b'Z', _) => parser.parse_timezone_offset(rest)?,
+                (_, 4) => parser.parse_timezone_offset(rest)?,
+                // GeneralizedTime allows an offset in whole hours
+                (sign, 2) if generalized => {
+                    let (hours, rest) = parse_n_digits(2, offset)?;
+                    parser.push_element(Element::Timeshift(Timeshift::offset(
+                        sign == b'+',
+                        Hour::new(hours).map_err(ParseError::from)?,
+                        Minute::new(0).map_err(ParseError::from)?,
+                    )));
+                    rest
+                }
+                _ => return Err(ParseError::Fail(rest).into()),
+            }
+        }
+        (_, Some(_)) => return Err(ParseError::Fail(rest).into()),
+        (_, None) => return Err(ParseError::UnexpectedEof { needed: 1 }.into()),
+    };
+    if !rest.is_empty() {
+        return Err(ParseError::Fail(rest).into());
+    }
+
+    let date_time = parser.build_precise_shifted_date_time()?;
+    let date = LocalDate::new(date_time.year, date_time.month, date_time.day);
+    if u8::from(date_time.day) > date.days_in_month()
+        || u8::from(date_time.hour) > 23
+        || u8::from(date_time.minute) > 59
+    {
+        return Err(BuildError::Invalid(components::Error::Range).into());
+    }
+    if let Timeshift::Offset { hours, minutes, .. } = date_time.timeshift {
+        if u8::from(hours) > 23 || u8::from(minutes) > 59 {
+            return Err(BuildError::Invalid(components::Error::Range).into());
+        }
+    }
+    Ok(date_time)
+}
+
+/// Format a date-time as a DER `UTCTime`, like `230917090858Z`
+///
+/// The date-time is converted to UTC and the fraction of a second is dropped.
+/// Fails with [`components::Error::Range`] for years outside 1950 to 2049.
+pub fn format_utc_time(date_time: &PreciseShiftedDateTime) -> Result<String, components::Error> {
+    let utc = date_time.to_timeshift(Timeshift::Utc)?;
+    let year = utc.year.value();
+    if !(1950..2050).contains(&year) {
+        return Err(components::Error::Range);
+    }
+    Ok(format!(
+        "{:02}{}{}{}{}{}Z",
+        year % 100,
+        utc.month,
+        utc.day,
+        utc.hour,
+        utc.minute,
+        utc.second
+    ))
+}
+
+/// Format a date-time as a DER `GeneralizedTime`, like `20230917090858.5Z`
+///
+/// The date-time is converted to UTC first.
+pub fn format_generalized_time(
+    date_time: &PreciseShiftedDateTime,
+) -> Result<String, components::Error> {
+    let utc = date_time.to_timeshift(Timeshift::Utc)?;
+    let mut formatted = format!(
+        "{}{}{}{}{}{}",
+        utc.year, utc.month, utc.day, utc.hour, utc.minute, utc.second
+    );
+    let nanosecond = u32::from(utc.nanosecond);
+    if nanosecond != 0 {
+        let fraction = format!("{:09}", nanosecond);
+        formatted.push('.');
+        formatted.push_str(fraction.trim_end_matches('0'));
+    }
+    formatted.push('Z');
+    Ok(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_generalized_time, format_utc_time, parse_generalized_time, parse_utc_time, Profile,
+    };
+    use crate::combined::PreciseShiftedDateTime;
+
+    #[test]
+    fn test_parse_utc_time() {
+        for (s, profile, expected) in [
+            ("230917090858Z", Profile::Der, "2023-09-17T09:08:58.0Z"),
+            ("490101000000Z", Profile::Der, "2049-01-01T00:00:00.0Z"),
+            ("500101000000Z", Profile::Der, "1950-01-01T00:00:00.0Z"),
+            ("991231235959Z", Profile::Der, "1999-12-31T23:59:59.0Z"),
+            ("161231235960Z", Profile::Der, "2016-12-31T23:59:60.0Z"),
+            ("230917090858Z", Profile::Ber, "2023-09-17T09:08:58.0Z"),
+            ("2309170908Z", Profile::Ber, "2023-09-17T09:08:00.0Z"),
+            (
+                "230917110858+0200",
+                Profile::Ber,
+                "2023-09-17T11:08:58.0+02:00",
+            ),
+            (
+                "2309170408-0500",
+                Profile::Ber,
+                "2023-09-17T04:08:00.0-05:00",
+            ),
+        ] {
+            let dt = parse_utc_time(s, profile);
+            assert!(dt.is_ok(), "{}: {:?}", s, dt);
+            assert_eq!(dt.unwrap().to_string(), expected, "{}", s);
+        }
+        for (s, profile) in [
+            ("2309170908Z", Profile::Der),
+            ("230917090858+0200", Profile::Der),
+            ("230917090858", Profile::Der),
+            ("230917090858z", Profile::Der),
+            ("230917090858.5Z", Profile::Der),
+            ("230917090858.5Z", Profile::Ber),
+            ("2309170908.5Z", Profile::Ber),
+            ("230917090858Z ", Profile::Der),
+            ("20230917090858Z", Profile::Der),
+            ("23091709Z", Profile::Ber),
+            ("230917090858+02", Profile::Ber),
+            ("230917090858+02:00", Profile::Ber),
+            ("230917090858", Profile::Ber),
+            ("230230090858Z", Profile::Der),
+            ("230917240000Z", Profile::Der),
+            ("230917096000Z", Profile::Der),
+            ("230917090860Z", Profile::Der),
+            ("2309-7090858Z", Profile::Der),
+            ("+30917090858Z", Profile::Der),
+            ("230917090858+2400", Profile::Ber),
+        ] {
+            let dt = parse_utc_time(s, profile);
+            assert!(dt.is_err(), "{}: {:?}", s, dt);
+        }
+    }
+
+    #[test]
+    fn test_parse_generalized_time() {
+        for (s, profile, expected) in [
+            ("20230917090858Z", Profile::Der, "2023-09-17T09:08:58.0Z"),
+            ("20230917090858.5Z", Profile::Der, "2023-09-17T09:08:58.5Z"),
+            (
+                "20230917090858.123456789Z",
+                Profile::Der,
+                "2023-09-17T09:08:58.123456789Z",
+            ),
+            ("19500101000000Z", Profile::Der, "1950-01-01T00:00:00.0Z"),
+            ("20230917090858.50Z", Profile::Ber, "2023-09-17T09:08:58.5Z"),
+            ("20230917090858,5Z", Profile::Ber, "2023-09-17T09:08:58.5Z"),
+            ("2023091709Z", Profile::Ber, "2023-09-17T09:00:00.0Z"),
+            ("202309170908Z", Profile::Ber, "2023-09-17T09:08:00.0Z"),
+            ("2023091709.5Z", Profile::Ber, "2023-09-17T09:30:00.0Z"),
+            ("2023091709,25Z", Profile::Ber, "2023-09-17T09:15:00.0Z"),
+            (
+                "2023091709.000000001Z",
+                Profile::Ber,
+                "2023-09-17T09:00:00.0000036Z",
+            ),
+            ("202309170908.5Z", Profile::Ber, "2023-09-17T09:08:30.0Z"),
+            (
+                "202309170908.123456789+0200",
+                Profile::Ber,
+                "2023-09-17T09:08:07.40740734+02:00",
+            ),
+            (
+                "20230917110858+0200",
+                Profile::Ber,
+                "2023-09-17T11:08:58.0+02:00",
+            ),
+            (
+                "20230917110858+02",
+                Profile::Ber,
+                "2023-09-17T11:08:58.0+02:00",
+            ),
+        ] {
+            let dt = parse_generalized_time(s, profile);
+            assert!(dt.is_ok(), "{}: {:?}", s, dt);
+            assert_eq!(dt.unwrap().to_string(), expected, "{}", s);
+        }
+        for (s, profile) in [
+            ("20230917090858.50Z", Profile::Der),
+            ("20230917090858.0Z", Profile::Der),
+            ("20230917090858.Z", Profile::Der),
+            ("20230917090858,5Z", Profile::Der),
+            ("20230917090858.Z", Profile::Ber),
+            ("202309170908Z", Profile::Der),
+            ("20230917090858+0200", Profile::Der),
+            ("20230917090858", Profile::Der),
+            ("20230917090858", Profile::Ber),
+            ("202309170908.5Z", Profile::Der),
+            ("2023091709.5Z", Profile::Der),
+            ("2023091709.Z", Profile::Ber),
+            ("2023091709.1234567891Z", Profile::Ber),
+            ("20230917090858.1234567891Z", Profile::Ber),
+            ("230917090858Z", Profile::Der),
+            ("2023-09-17T09:08:58Z", Profile::Ber),
+        ] {
+            let dt = parse_generalized_time(s, profile);
+            assert!(dt.is_err(), "{}: {:?}", s, dt);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        for (s, utc_time, generalized_time) in [
+            ("2023-09-17T09:08:58Z", "230917090858Z", "20230917090858Z"),
+            (
+                "2023-09-17T11:08:58.5+02:00",
+                "230917090858Z",
+                "20230917090858.5Z",
+            ),
+            (
+                "1950-01-01T00:00:00.000000001Z",
+                "500101000000Z",
+                "19500101000000.000000001Z",
+            ),
+            (
+                "2016-12-31T23:59:60.25Z",
+                "161231235960Z",
+                "20161231235960.25Z",
+            ),
+        ] {
+            let dt: PreciseShiftedDateTime = s.parse().unwrap();
+            assert_eq!(format_utc_time(&dt).unwrap(), utc_time);
+            assert_eq!(format_generalized_time(&dt).unwrap(), generalized_time);
+            let parsed = parse_generalized_time(generalized_time, super::Profile::Der).unwrap();
+            assert_eq!(parsed.unix_timestamp(), dt.unix_timestamp());
+        }
+        for s in ["2050-01-01T00:00:00Z", "1949-12-31T23:59:59Z"] {
+            let dt: PreciseShiftedDateTime = s.parse().unwrap();
+            assert!(format_utc_time(&dt).is_err());
+            assert!(format_generalized_time(&dt).is_ok());
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Proleptic Gregorian calendar arithmetic shared by the date types.
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => unreachable!("month is range checked"),
+    }
+}
+
+/// Number of weeks in an ISO 8601 week-numbering year, 52 or 53
+pub(crate) fn iso_weeks_in_year(year: i32) -> u8 {
+    // Years starting on a Thursday, and leap years starting on a Wednesday,
+    // have 53 weeks.
+    let weekday_of_january_first = (days_from_civil(year, 1, 1) + 3).rem_euclid(7) + 1;
+    match weekday_of_january_first {
+        4 => 53,
+        3 if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+/// Days since 1970-01-01 of a date
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Date of a number of days since 1970-01-01, as year, month and day
+///
+/// Every `i64` has a date, though the year can be far outside of `i32`.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    // Days since 0000-03-01, which doesn't fit in an i64 near i64::MAX
+    let days = days as i128 + 719468;
+    let era = days.div_euclid(146097) as i64;
+    let day_of_era = days.rem_euclid(146097) as i64;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil};
+
+    #[test]
+    fn test_days_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(0, 1, 1), -719528);
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year as i32, month, day), days);
+        }
+        let (year, _, _) = civil_from_days(i64::MAX);
+        assert!(year > i32::MAX as i64);
+        let (year, _, _) = civil_from_days(i64::MIN);
+        assert!(year < i32::MIN as i64);
+    }
+}
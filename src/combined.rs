@@ -1,9 +1,354 @@
-use core::fmt;
+use core::{fmt, ops::Add};
 
 #[cfg(feature = "chrono")]
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use crate::components::ExtendedYear;
+#[cfg(feature = "chrono")]
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike, Utc,
+};
+#[cfg(feature = "jiff")]
+use jiff::Timestamp;
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::{
+    calendar,
+    components::{
+        Day, Error, Hour, Minute, Month, Nanosecond, Second, SimpleYear, Timeshift, Week, Weekday,
+        YearDigits,
+    },
+    duration::{Duration, MonthDuration, YearDuration},
+    Year,
+};
+
+const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000;
+
+fn time_of_day_nanoseconds(
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+) -> i128 {
+    let hour: i64 = hour.into();
+    let minute: i64 = minute.into();
+    let second: i64 = second.into();
+    let nanosecond: i64 = nanosecond.into();
+    ((hour * 60 + minute) * 60 + second) as i128 * 1_000_000_000 + nanosecond as i128
+}
+
+/// Check the rules between time components that a single component can't check.
+///
+/// Hour 24 is only allowed as `24:00:00` without a fraction, the end of a day.
+/// Second 60 is only allowed during the last minute of the UTC day. Without a
+/// time shift that minute is unknown, so second 60 is allowed in every minute.
+pub(crate) fn check_time(
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+    timeshift: Option<Timeshift>,
+) -> Result<(), Error> {
+    let hour: i32 = hour.into();
+    let minute: i32 = minute.into();
+    let second: i32 = second.into();
+    let nanosecond: u32 = nanosecond.into();
+    if hour == 24 && (minute != 0 || second != 0 || nanosecond != 0) {
+        return Err(Error::EndOfDay);
+    }
+    if second == 60 {
+        if hour == 24 {
+            return Err(Error::EndOfDay);
+        }
+        if let Some(timeshift) = timeshift {
+            let utc_minute =
+                (hour * 60 + minute - timeshift.seconds_from_east() / 60).rem_euclid(24 * 60);
+            if utc_minute != 24 * 60 - 1 {
+                return Err(Error::LeapSecond);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Move `24:00` to `00:00` of the next day, other hours are returned unchanged
+///
+/// The year is a plain number, which the caller checks against its own year type.
+pub(crate) fn end_of_day(
+    year: i32,
+    month: Month,
+    day: Day,
+    hour: Hour,
+) -> Result<(i32, Month, Day, Hour), Error> {
+    if u8::from(hour) != 24 {
+        return Ok((year, month, day, hour));
+    }
+    let days = calendar::days_from_civil(year, month.into(), day.into()) + 1;
+    let (year, month, day) = calendar::civil_from_days(days);
+    Ok((
+        year.try_into().map_err(|_| Error::Range)?,
+        month.try_into()?,
+        day.try_into()?,
+        Hour::new(0)?,
+    ))
+}
+
+macro_rules! impl_normalize_end_of_day {
+    ($type:ident) => {
+        impl<Y: YearDigits> $type<Y> {
+            /// Turn `24:00:00` into `00:00:00` of the next day
+            ///
+            /// Other times are returned unchanged.
+            pub fn normalize_end_of_day(self) -> Result<Self, Error> {
+                let (year, month, day, hour) =
+                    end_of_day(self.year.value(), self.month, self.day, self.hour)?;
+                Ok(Self {
+                    year: Y::from_digits(year)?,
+                    month,
+                    day,
+                    hour,
+                    ..self
+                })
+            }
+        }
+    };
+}
+
+/// Convert time components to chrono, and whether the time rolled over into the next day.
+///
+/// chrono has no hour 24, so `24:00:00` becomes `00:00:00` of the next day. A
+/// leap second is stored by chrono as second 59 with a nanosecond of at least
+/// 1_000_000_000, so `23:59:60.5` becomes `23:59:59` with 1_500_000_000 nanoseconds.
+#[cfg(feature = "chrono")]
+fn to_chrono_time(
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+) -> (NaiveTime, bool) {
+    let hour: u32 = hour.into();
+    let minute: u32 = minute.into();
+    let second: u32 = second.into();
+    let nanosecond: u32 = nanosecond.into();
+    let (hour, next_day) = if hour == 24 { (0, true) } else { (hour, false) };
+    let (second, nanosecond) = if second == 60 {
+        (59, nanosecond + 1_000_000_000)
+    } else {
+        (second, nanosecond)
+    };
+    let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond)
+        .expect("internal values are already range checked");
+    (time, next_day)
+}
+
+#[cfg(feature = "chrono")]
+fn to_chrono_date<Y>(year: Year<Y>, month: Month, day: Day, next_day: bool) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(year.value(), month.into(), day.into())?;
+    if next_day {
+        date.succ_opt()
+    } else {
+        Some(date)
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn to_chrono_date_time<Y>(
+    year: Year<Y>,
+    month: Month,
+    day: Day,
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+) -> Option<NaiveDateTime> {
+    let (time, next_day) = to_chrono_time(hour, minute, second, nanosecond);
+    Some(NaiveDateTime::new(
+        to_chrono_date(year, month, day, next_day)?,
+        time,
+    ))
+}
+
+#[cfg(feature = "chrono")]
+fn to_chrono_fixed_offset(
+    date_time: Option<NaiveDateTime>,
+    timeshift: Timeshift,
+) -> Option<DateTime<FixedOffset>> {
+    FixedOffset::east_opt(timeshift.seconds_from_east())?
+        .from_local_datetime(&date_time?)
+        .single()
+}
+
+#[cfg(feature = "chrono")]
+fn to_chrono_utc(
+    date_time: Option<NaiveDateTime>,
+    timeshift: Timeshift,
+) -> Result<DateTime<Utc>, Error> {
+    match timeshift {
+        Timeshift::Utc => Ok(Utc.from_utc_datetime(&date_time.ok_or(Error::Range)?)),
+        Timeshift::Offset { .. } => Err(Error::NotUtc),
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn from_chrono_date<Y: YearDigits>(date: NaiveDate) -> Result<LocalDate<Y>, Error> {
+    Ok(LocalDate {
+        year: Y::from_digits(date.year())?,
+        month: Month::new(date.month().into())?,
+        day: Day::new(date.day().into())?,
+    })
+}
+
+/// Convert a time from chrono, where a leap second is second 59 with a
+/// nanosecond of at least 1_000_000_000, so `23:59:59` with 1_500_000_000
+/// nanoseconds becomes `23:59:60.5`.
+#[cfg(feature = "chrono")]
+fn from_chrono_time(time: NaiveTime) -> PreciseLocalTime {
+    let (second, nanosecond) = match time.nanosecond() {
+        nanosecond if nanosecond >= 1_000_000_000 => (60, nanosecond - 1_000_000_000),
+        nanosecond => (time.second(), nanosecond),
+    };
+    PreciseLocalTime {
+        hour: Hour::new(time.hour().into()).expect("chrono has hours below 24"),
+        minute: Minute::new(time.minute().into()).expect("chrono has minutes below 60"),
+        second: Second::new(second.into()).expect("chrono has seconds up to 60"),
+        nanosecond: Nanosecond::new(nanosecond.into())
+            .expect("chrono has nanoseconds below 2 * 10^9"),
+    }
+}
+
+#[cfg(feature = "time")]
+fn to_time_date<Y>(year: Year<Y>, month: Month, day: Day) -> Result<time::Date, Error> {
+    if u8::from(day) > year.days_in_month(month) {
+        return Err(Error::DayOverflow);
+    }
+    let month = time::Month::try_from(u8::from(month)).map_err(|_| Error::Range)?;
+    time::Date::from_calendar_date(year.value(), month, day.into()).map_err(|_| Error::Range)
+}
+
+/// Convert date-time components to `time`, which has no end of day and no leap seconds.
+///
+/// `24:00:00` becomes `00:00:00` of the next day, and a leap second fails with
+/// [`Error::LeapSecond`].
+#[cfg(feature = "time")]
+fn to_time_date_time<Y>(
+    year: Year<Y>,
+    month: Month,
+    day: Day,
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+) -> Result<PrimitiveDateTime, Error> {
+    let date = to_time_date(year, month, day)?;
+    if u8::from(second) == 60 {
+        return Err(Error::LeapSecond);
+    }
+    if u8::from(hour) == 24 {
+        let date = date.next_day().ok_or(Error::Range)?;
+        return Ok(PrimitiveDateTime::new(date, time::Time::MIDNIGHT));
+    }
+    let time =
+        time::Time::from_hms_nano(hour.into(), minute.into(), second.into(), nanosecond.into())
+            .map_err(|_| Error::Range)?;
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+#[cfg(feature = "time")]
+fn from_time_date<Y: YearDigits>(date: time::Date) -> Result<LocalDate<Y>, Error> {
+    Ok(LocalDate {
+        year: Y::from_digits(date.year())?,
+        month: Month::new(u8::from(date.month()).into())?,
+        day: Day::new(date.day().into())?,
+    })
+}
+
+#[cfg(feature = "time")]
+fn from_time_time(time: time::Time) -> PreciseLocalTime {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    PreciseLocalTime {
+        hour: Hour::new(hour.into()).expect("time has hours below 24"),
+        minute: Minute::new(minute.into()).expect("time has minutes below 60"),
+        second: Second::new(second.into()).expect("time has seconds below 60"),
+        nanosecond: Nanosecond::new(nanosecond.into()).expect("time has nanoseconds below 10^9"),
+    }
+}
 
-use crate::{components::{Day, Error, Hour, Minute, Month, Nanosecond, Second, Timeshift, SimpleYear}, Year};
+/// Convert date components to jiff, which only supports the years -9999 to 9999
+#[cfg(feature = "jiff")]
+fn to_jiff_date<Y>(year: Year<Y>, month: Month, day: Day) -> Result<jiff::civil::Date, Error> {
+    if u8::from(day) > year.days_in_month(month) {
+        return Err(Error::DayOverflow);
+    }
+    let year = i16::try_from(year.value()).map_err(|_| Error::Range)?;
+    jiff::civil::Date::new(year, u8::from(month) as i8, u8::from(day) as i8)
+        .map_err(|_| Error::Range)
+}
+
+/// Convert date-time components to jiff, which has no end of day and no leap seconds.
+///
+/// `24:00:00` becomes `00:00:00` of the next day, and a leap second fails with
+/// [`Error::LeapSecond`].
+#[cfg(feature = "jiff")]
+fn to_jiff_date_time<Y>(
+    year: Year<Y>,
+    month: Month,
+    day: Day,
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+    nanosecond: Nanosecond,
+) -> Result<jiff::civil::DateTime, Error> {
+    let date = to_jiff_date(year, month, day)?;
+    if u8::from(second) == 60 {
+        return Err(Error::LeapSecond);
+    }
+    if u8::from(hour) == 24 {
+        let date = date.tomorrow().map_err(|_| Error::Range)?;
+        return Ok(jiff::civil::DateTime::from_parts(
+            date,
+            jiff::civil::Time::midnight(),
+        ));
+    }
+    let time = jiff::civil::Time::new(
+        u8::from(hour) as i8,
+        u8::from(minute) as i8,
+        u8::from(second) as i8,
+        u32::from(nanosecond) as i32,
+    )
+    .map_err(|_| Error::Range)?;
+    Ok(jiff::civil::DateTime::from_parts(date, time))
+}
+
+#[cfg(feature = "jiff")]
+fn from_jiff_date<Y: YearDigits>(date: jiff::civil::Date) -> Result<LocalDate<Y>, Error> {
+    Ok(LocalDate {
+        year: Y::from_digits(date.year().into())?,
+        month: Month::new(date.month() as u64)?,
+        day: Day::new(date.day() as u64)?,
+    })
+}
+
+#[cfg(feature = "jiff")]
+fn from_jiff_time(time: jiff::civil::Time) -> PreciseLocalTime {
+    PreciseLocalTime {
+        hour: Hour::new(time.hour() as u64).expect("jiff has hours below 24"),
+        minute: Minute::new(time.minute() as u64).expect("jiff has minutes below 60"),
+        second: Second::new(time.second() as u64).expect("jiff has seconds below 60"),
+        nanosecond: Nanosecond::new(time.subsec_nanosecond() as u64)
+            .expect("jiff has nanoseconds below 10^9"),
+    }
+}
+
+/// What to do when date arithmetic ends on a day past the end of a month
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthOverflow {
+    /// Use the last day of the month, so 2023-01-31 plus one month is 2023-02-28
+    Clamp,
+    /// Continue into the next month, so 2023-01-31 plus one month is 2023-03-03
+    Spill,
+    /// Fail with [`Error::DayOverflow`]
+    Reject,
+}
 
 /// Date without time shift information
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -19,7 +364,138 @@ impl<Y> LocalDate<Y> {
     }
 }
 
-impl fmt::Display for LocalDate {
+impl<Y> LocalDate<Y> {
+    /// Days since 1970-01-01
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        calendar::days_from_civil(self.year.value(), self.month.into(), self.day.into())
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        Weekday::new((self.days_since_epoch() + 3).rem_euclid(7) as u64 + 1)
+            .expect("value is always between 1 and 7")
+    }
+
+    /// Day of the year, starting at 1 for the 1st of January
+    pub fn day_of_year(&self) -> u16 {
+        (self.days_since_epoch() - calendar::days_from_civil(self.year.value(), 1, 1) + 1) as u16
+    }
+
+    /// Number of days in the month of this date
+    pub fn days_in_month(&self) -> u8 {
+        self.year.days_in_month(self.month)
+    }
+
+    /// The ISO 8601 week number and the year that week belongs to
+    fn iso_week_date(&self) -> (i32, u8) {
+        let year = self.year.value();
+        let weekday: i32 = self.weekday().into();
+        let week = (self.day_of_year() as i32 - weekday + 10) / 7;
+        if week < 1 {
+            (year - 1, calendar::iso_weeks_in_year(year - 1))
+        } else if week > calendar::iso_weeks_in_year(year) as i32 {
+            (year + 1, 1)
+        } else {
+            (year, week as u8)
+        }
+    }
+
+    /// Week of the year as defined by ISO 8601
+    ///
+    /// Weeks start on Monday, and week 1 is the week with the first Thursday
+    /// of the year. The first days of January can therefore be in the last week
+    /// of the previous year, and the last days of December in week 1 of the
+    /// next year, see [`LocalDate::iso_week_year`].
+    pub fn iso_week(&self) -> Week {
+        Week::new(self.iso_week_date().1 as u64).expect("value is always between 1 and 53")
+    }
+}
+
+impl<Y: YearDigits> LocalDate<Y> {
+    /// The year that the [`LocalDate::iso_week`] of this date belongs to
+    pub fn iso_week_year(&self) -> Result<Year<Y>, Error> {
+        Y::from_digits(self.iso_week_date().0)
+    }
+
+    pub(crate) fn from_days_since_epoch(days: i64) -> Result<Self, Error> {
+        let (year, month, day) = calendar::civil_from_days(days);
+        Ok(Self {
+            year: Y::from_digits(year.try_into().map_err(|_| Error::Range)?)?,
+            month: month.try_into()?,
+            day: day.try_into()?,
+        })
+    }
+
+    /// Add a number of days, or subtract them when negative
+    ///
+    /// Fails with [`Error::Range`] when the result does not fit in the year type.
+    pub fn checked_add_days(self, days: i64) -> Result<Self, Error> {
+        let days = self
+            .days_since_epoch()
+            .checked_add(days)
+            .ok_or(Error::Range)?;
+        Self::from_days_since_epoch(days)
+    }
+
+    pub fn checked_sub_days(self, days: i64) -> Result<Self, Error> {
+        self.checked_add_days(days.checked_neg().ok_or(Error::Range)?)
+    }
+
+    /// Add a number of months, or subtract them when negative
+    ///
+    /// When the day does not exist in the resulting month, `overflow` decides
+    /// what happens. Fails with [`Error::Range`] when the result does not fit
+    /// in the year type.
+    pub fn checked_add_months(self, months: i64, overflow: MonthOverflow) -> Result<Self, Error> {
+        let month: i64 = self.month.into();
+        let total = (self.year.value() as i64 * 12 + month - 1)
+            .checked_add(months)
+            .ok_or(Error::Range)?;
+        let year: i32 = total.div_euclid(12).try_into().map_err(|_| Error::Range)?;
+        let month = (total.rem_euclid(12) + 1) as u8;
+        let day: u8 = self.day.into();
+        let days_in_month = calendar::days_in_month(year, month);
+        if day <= days_in_month {
+            return Ok(Self::new(
+                Y::from_digits(year)?,
+                month.try_into()?,
+                self.day,
+            ));
+        }
+        match overflow {
+            MonthOverflow::Clamp => Ok(Self::new(
+                Y::from_digits(year)?,
+                month.try_into()?,
+                days_in_month.try_into()?,
+            )),
+            MonthOverflow::Spill => {
+                let last = calendar::days_from_civil(year, month, days_in_month);
+                Self::from_days_since_epoch(last + (day - days_in_month) as i64)
+            }
+            MonthOverflow::Reject => Err(Error::DayOverflow),
+        }
+    }
+
+    pub fn checked_sub_months(self, months: i64, overflow: MonthOverflow) -> Result<Self, Error> {
+        self.checked_add_months(months.checked_neg().ok_or(Error::Range)?, overflow)
+    }
+
+    /// Add a number of years, or subtract them when negative
+    ///
+    /// Only the 29th of February can fall outside the resulting month, in
+    /// which case `overflow` decides what happens.
+    pub fn checked_add_years(self, years: i64, overflow: MonthOverflow) -> Result<Self, Error> {
+        self.checked_add_months(years.checked_mul(12).ok_or(Error::Range)?, overflow)
+    }
+
+    pub fn checked_sub_years(self, years: i64, overflow: MonthOverflow) -> Result<Self, Error> {
+        self.checked_add_years(years.checked_neg().ok_or(Error::Range)?, overflow)
+    }
+}
+
+impl<Y> fmt::Display for LocalDate<Y>
+where
+    Year<Y>: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}-{}-{}", self.year, self.month, self.day)
     }
@@ -44,11 +520,67 @@ where
 #[cfg(feature = "chrono")]
 impl From<LocalDate> for NaiveDate {
     fn from(val: LocalDate) -> Self {
-        NaiveDate::from_ymd_opt(val.year.into(), val.month.into(), val.day.into())
+        to_chrono_date(val.year, val.month, val.day, false)
             .expect("internal values are already range checked")
     }
 }
 
+/// Fails for years outside of chrono's range
+#[cfg(feature = "chrono")]
+impl<const N: usize> TryFrom<LocalDate<ExtendedYear<N>>> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(value: LocalDate<ExtendedYear<N>>) -> Result<Self, Self::Error> {
+        to_chrono_date(value.year, value.month, value.day, false).ok_or(Error::Range)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Y: YearDigits> TryFrom<NaiveDate> for LocalDate<Y> {
+    type Error = Error;
+
+    fn try_from(value: NaiveDate) -> Result<Self, Self::Error> {
+        from_chrono_date(value)
+    }
+}
+
+#[cfg(feature = "time")]
+impl<Y> TryFrom<LocalDate<Y>> for time::Date {
+    type Error = Error;
+
+    fn try_from(value: LocalDate<Y>) -> Result<Self, Self::Error> {
+        to_time_date(value.year, value.month, value.day)
+    }
+}
+
+#[cfg(feature = "time")]
+impl<Y: YearDigits> TryFrom<time::Date> for LocalDate<Y> {
+    type Error = Error;
+
+    fn try_from(value: time::Date) -> Result<Self, Self::Error> {
+        from_time_date(value)
+    }
+}
+
+/// Fails for years outside of -9999 to 9999, which jiff does not support
+#[cfg(feature = "jiff")]
+impl<Y> TryFrom<LocalDate<Y>> for jiff::civil::Date {
+    type Error = Error;
+
+    fn try_from(value: LocalDate<Y>) -> Result<Self, Self::Error> {
+        to_jiff_date(value.year, value.month, value.day)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl<Y: YearDigits> TryFrom<jiff::civil::Date> for LocalDate<Y> {
+    type Error = Error;
+
+    fn try_from(value: jiff::civil::Date) -> Result<Self, Self::Error> {
+        from_jiff_date(value)
+    }
+}
+
 /// Time without time shift information
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LocalTime {
@@ -82,19 +614,33 @@ where
     type Error = Error;
 
     fn try_from((hour, minute, second): (H, M, S)) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let time = Self {
             hour: hour.try_into()?,
             minute: minute.try_into()?,
             second: second.try_into()?,
-        })
+        };
+        check_time(
+            time.hour,
+            time.minute,
+            time.second,
+            Nanosecond::new(0)?,
+            None,
+        )?;
+        Ok(time)
     }
 }
 
+/// `24:00:00` becomes `00:00:00`, since [`NaiveTime`] has no end of day
 #[cfg(feature = "chrono")]
 impl From<LocalTime> for NaiveTime {
     fn from(val: LocalTime) -> Self {
-        NaiveTime::from_hms_opt(val.hour.into(), val.minute.into(), val.second.into())
-            .expect("internal values are already range checked")
+        to_chrono_time(
+            val.hour,
+            val.minute,
+            val.second,
+            Nanosecond::new(0).unwrap(),
+        )
+        .0
     }
 }
 
@@ -140,25 +686,94 @@ where
     type Error = Error;
 
     fn try_from((hour, minute, second, nanosecond): (H, M, S, N)) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let time = Self {
             hour: hour.try_into()?,
             minute: minute.try_into()?,
             second: second.try_into()?,
             nanosecond: nanosecond.try_into()?,
-        })
+        };
+        check_time(time.hour, time.minute, time.second, time.nanosecond, None)?;
+        Ok(time)
     }
 }
 
+/// `24:00:00` becomes `00:00:00`, since [`NaiveTime`] has no end of day
 #[cfg(feature = "chrono")]
 impl From<PreciseLocalTime> for NaiveTime {
     fn from(val: PreciseLocalTime) -> Self {
-        NaiveTime::from_hms_nano_opt(
-            val.hour.into(),
-            val.minute.into(),
-            val.second.into(),
-            val.nanosecond.into(),
+        to_chrono_time(val.hour, val.minute, val.second, val.nanosecond).0
+    }
+}
+
+/// A chrono leap second becomes second 60
+#[cfg(feature = "chrono")]
+impl From<NaiveTime> for PreciseLocalTime {
+    fn from(value: NaiveTime) -> Self {
+        from_chrono_time(value)
+    }
+}
+
+/// Fails for leap seconds, which [`time::Time`] does not have, and for `24:00:00`
+///
+/// Date-times at `24:00:00` become the start of the next day, but a time on
+/// its own has no next day to move to.
+#[cfg(feature = "time")]
+impl TryFrom<PreciseLocalTime> for time::Time {
+    type Error = Error;
+
+    fn try_from(value: PreciseLocalTime) -> Result<Self, Self::Error> {
+        if u8::from(value.hour) == 24 {
+            return Err(Error::EndOfDay);
+        }
+        if u8::from(value.second) == 60 {
+            return Err(Error::LeapSecond);
+        }
+        time::Time::from_hms_nano(
+            value.hour.into(),
+            value.minute.into(),
+            value.second.into(),
+            value.nanosecond.into(),
         )
-        .expect("internal values are already range checked")
+        .map_err(|_| Error::Range)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Time> for PreciseLocalTime {
+    fn from(value: time::Time) -> Self {
+        from_time_time(value)
+    }
+}
+
+/// Fails for leap seconds, which [`jiff::civil::Time`] does not have, and for `24:00:00`
+///
+/// Date-times at `24:00:00` become the start of the next day, but a time on
+/// its own has no next day to move to.
+#[cfg(feature = "jiff")]
+impl TryFrom<PreciseLocalTime> for jiff::civil::Time {
+    type Error = Error;
+
+    fn try_from(value: PreciseLocalTime) -> Result<Self, Self::Error> {
+        if u8::from(value.hour) == 24 {
+            return Err(Error::EndOfDay);
+        }
+        if u8::from(value.second) == 60 {
+            return Err(Error::LeapSecond);
+        }
+        jiff::civil::Time::new(
+            u8::from(value.hour) as i8,
+            u8::from(value.minute) as i8,
+            u8::from(value.second) as i8,
+            u32::from(value.nanosecond) as i32,
+        )
+        .map_err(|_| Error::Range)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::Time> for PreciseLocalTime {
+    fn from(value: jiff::civil::Time) -> Self {
+        from_jiff_time(value)
     }
 }
 
@@ -193,7 +808,12 @@ impl<Y> LocalDateTime<Y> {
     }
 }
 
-impl fmt::Display for LocalDateTime {
+impl_normalize_end_of_day!(LocalDateTime);
+
+impl<Y> fmt::Display for LocalDateTime<Y>
+where
+    Year<Y>: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -217,26 +837,57 @@ where
     fn try_from(
         (year, month, day, hour, minute, second): (Y, Mo, D, H, Mi, S),
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let date_time = Self {
             year: year.try_into()?,
             month: month.try_into()?,
             day: day.try_into()?,
             hour: hour.try_into()?,
             minute: minute.try_into()?,
             second: second.try_into()?,
-        })
+        };
+        check_time(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            Nanosecond::new(0)?,
+            None,
+        )?;
+        Ok(date_time)
     }
 }
 
 #[cfg(feature = "chrono")]
 impl From<LocalDateTime> for NaiveDateTime {
     fn from(val: LocalDateTime) -> Self {
-        NaiveDateTime::new(
-            NaiveDate::from_ymd_opt(val.year.into(), val.month.into(), val.day.into())
-                .expect("internal values are already range checked"),
-            NaiveTime::from_hms_opt(val.hour.into(), val.minute.into(), val.second.into())
-                .expect("internal values are already range checked"),
+        to_chrono_date_time(
+            val.year,
+            val.month,
+            val.day,
+            val.hour,
+            val.minute,
+            val.second,
+            Nanosecond::new(0).unwrap(),
+        )
+        .expect("internal values are already range checked")
+    }
+}
+
+/// Fails for years outside of chrono's range
+#[cfg(feature = "chrono")]
+impl<const N: usize> TryFrom<LocalDateTime<ExtendedYear<N>>> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(value: LocalDateTime<ExtendedYear<N>>) -> Result<Self, Self::Error> {
+        to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            Nanosecond::new(0)?,
         )
+        .ok_or(Error::Range)
     }
 }
 
@@ -274,7 +925,12 @@ impl<Y> PreciseLocalDateTime<Y> {
     }
 }
 
-impl fmt::Display for PreciseLocalDateTime {
+impl_normalize_end_of_day!(PreciseLocalDateTime);
+
+impl<Y> fmt::Display for PreciseLocalDateTime<Y>
+where
+    Year<Y>: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let ns_string = format!("{:0>9}", self.nanosecond);
         let ns = if self.nanosecond == Nanosecond::new(0).unwrap() {
@@ -305,7 +961,7 @@ where
     fn try_from(
         (year, month, day, hour, minute, second, nanosecond): (Y, Mo, D, H, Mi, S, N),
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let date_time = Self {
             year: year.try_into()?,
             month: month.try_into()?,
             day: day.try_into()?,
@@ -313,27 +969,147 @@ where
             minute: minute.try_into()?,
             second: second.try_into()?,
             nanosecond: nanosecond.try_into()?,
-        })
+        };
+        check_time(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            date_time.nanosecond,
+            None,
+        )?;
+        Ok(date_time)
     }
 }
 
 #[cfg(feature = "chrono")]
 impl From<PreciseLocalDateTime> for NaiveDateTime {
     fn from(val: PreciseLocalDateTime) -> Self {
-        NaiveDateTime::new(
-            NaiveDate::from_ymd_opt(val.year.into(), val.month.into(), val.day.into())
-                .expect("internal values are already range checked"),
-            NaiveTime::from_hms_nano_opt(
-                val.hour.into(),
-                val.minute.into(),
-                val.second.into(),
-                val.nanosecond.into(),
-            )
-            .expect("internal values are already range checked"),
+        to_chrono_date_time(
+            val.year,
+            val.month,
+            val.day,
+            val.hour,
+            val.minute,
+            val.second,
+            val.nanosecond,
+        )
+        .expect("internal values are already range checked")
+    }
+}
+
+/// Fails for years outside of chrono's range
+#[cfg(feature = "chrono")]
+impl<const N: usize> TryFrom<PreciseLocalDateTime<ExtendedYear<N>>> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(value: PreciseLocalDateTime<ExtendedYear<N>>) -> Result<Self, Self::Error> {
+        to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        )
+        .ok_or(Error::Range)
+    }
+}
+
+/// A chrono leap second becomes second 60
+#[cfg(feature = "chrono")]
+impl<Y: YearDigits> TryFrom<NaiveDateTime> for PreciseLocalDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: NaiveDateTime) -> Result<Self, Self::Error> {
+        let date = from_chrono_date(value.date())?;
+        let time = from_chrono_time(value.time());
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+        })
+    }
+}
+
+/// `24:00:00` becomes `00:00:00` of the next day, leap seconds fail
+#[cfg(feature = "time")]
+impl<Y> TryFrom<PreciseLocalDateTime<Y>> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(value: PreciseLocalDateTime<Y>) -> Result<Self, Self::Error> {
+        to_time_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
         )
     }
 }
 
+#[cfg(feature = "time")]
+impl<Y: YearDigits> TryFrom<PrimitiveDateTime> for PreciseLocalDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        let date = from_time_date(value.date())?;
+        let time = from_time_time(value.time());
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+        })
+    }
+}
+
+/// `24:00:00` becomes `00:00:00` of the next day, leap seconds fail
+#[cfg(feature = "jiff")]
+impl<Y> TryFrom<PreciseLocalDateTime<Y>> for jiff::civil::DateTime {
+    type Error = Error;
+
+    fn try_from(value: PreciseLocalDateTime<Y>) -> Result<Self, Self::Error> {
+        to_jiff_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        )
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl<Y: YearDigits> TryFrom<jiff::civil::DateTime> for PreciseLocalDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: jiff::civil::DateTime) -> Result<Self, Self::Error> {
+        let date = from_jiff_date(value.date())?;
+        let time = from_jiff_time(value.time());
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+        })
+    }
+}
+
 /// Date and time with time shift information
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ShiftedDateTime<Y = SimpleYear> {
@@ -368,7 +1144,12 @@ impl<Y> ShiftedDateTime<Y> {
     }
 }
 
-impl fmt::Display for ShiftedDateTime {
+impl_normalize_end_of_day!(ShiftedDateTime);
+
+impl<Y> fmt::Display for ShiftedDateTime<Y>
+where
+    Year<Y>: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -393,7 +1174,7 @@ where
     fn try_from(
         (year, month, day, hour, minute, second, timeshift): (Y, Mo, D, H, Mi, S, T),
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let date_time = Self {
             year: year.try_into()?,
             month: month.try_into()?,
             day: day.try_into()?,
@@ -401,51 +1182,70 @@ where
             minute: minute.try_into()?,
             second: second.try_into()?,
             timeshift: timeshift.try_into()?,
-        })
+        };
+        check_time(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            Nanosecond::new(0)?,
+            Some(date_time.timeshift),
+        )?;
+        Ok(date_time)
     }
 }
 
 #[cfg(feature = "chrono")]
 impl From<ShiftedDateTime> for DateTime<FixedOffset> {
     fn from(val: ShiftedDateTime) -> Self {
-        DateTime::from_local(
-            NaiveDateTime::new(
-                NaiveDate::from_ymd_opt(val.year.into(), val.month.into(), val.day.into())
-                    .expect("internal values are already range checked"),
-                NaiveTime::from_hms_opt(val.hour.into(), val.minute.into(), val.second.into())
-                    .expect("internal values are already range checked"),
-            ),
-            FixedOffset::east_opt(val.timeshift.seconds_from_east())
-                .expect("internal values are already range checked"),
-        )
+        let date_time = to_chrono_date_time(
+            val.year,
+            val.month,
+            val.day,
+            val.hour,
+            val.minute,
+            val.second,
+            Nanosecond::new(0).unwrap(),
+        );
+        to_chrono_fixed_offset(date_time, val.timeshift)
+            .expect("internal values are already range checked")
     }
 }
 
+/// Fails for years outside of chrono's range
 #[cfg(feature = "chrono")]
-impl TryInto<DateTime<Utc>> for ShiftedDateTime {
-    type Error = ();
-
-    fn try_into(self) -> Result<DateTime<Utc>, Self::Error> {
-        match self.timeshift {
-            Timeshift::Utc => Ok(DateTime::<Utc>::from_local(
-                NaiveDateTime::new(
-                    NaiveDate::from_ymd_opt(self.year.into(), self.month.into(), self.day.into())
-                        .expect("internal values are already range checked"),
-                    NaiveTime::from_hms_opt(
-                        self.hour.into(),
-                        self.minute.into(),
-                        self.second.into(),
-                    )
-                    .expect("internal values are already range checked"),
-                ),
-                Utc,
-            )),
-            Timeshift::Offset {
-                non_negative: _,
-                hours: _,
-                minutes: _,
-            } => Err(()),
-        }
+impl<const N: usize> TryFrom<ShiftedDateTime<ExtendedYear<N>>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(value: ShiftedDateTime<ExtendedYear<N>>) -> Result<Self, Self::Error> {
+        let date_time = to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            Nanosecond::new(0)?,
+        );
+        to_chrono_fixed_offset(date_time, value.timeshift).ok_or(Error::Range)
+    }
+}
+
+/// Fails with [`Error::NotUtc`] unless the time shift is [`Timeshift::Utc`]
+#[cfg(feature = "chrono")]
+impl<Y> TryFrom<ShiftedDateTime<Y>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(value: ShiftedDateTime<Y>) -> Result<Self, Self::Error> {
+        let date_time = to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            Nanosecond::new(0)?,
+        );
+        to_chrono_utc(date_time, value.timeshift)
     }
 }
 
@@ -487,7 +1287,177 @@ impl<Y> PreciseShiftedDateTime<Y> {
     }
 }
 
-impl fmt::Display for PreciseShiftedDateTime {
+impl_normalize_end_of_day!(PreciseShiftedDateTime);
+
+impl<Y: YearDigits> PreciseShiftedDateTime<Y> {
+    /// Seconds since 1970-01-01T00:00:00Z, not counting leap seconds
+    ///
+    /// Like POSIX time, a leap second has the same timestamp as the second after it.
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = calendar::days_from_civil(self.year.value(), self.month.into(), self.day.into());
+        let hour: i64 = self.hour.into();
+        let minute: i64 = self.minute.into();
+        let second: i64 = self.second.into();
+        days * 86400 + hour * 3600 + minute * 60 + second
+            - self.timeshift.seconds_from_east() as i64
+    }
+
+    /// The UTC date-time at a number of seconds since 1970-01-01T00:00:00Z
+    pub fn from_unix_timestamp(seconds: i64, nanosecond: Nanosecond) -> Result<Self, Error> {
+        let (year, month, day) = calendar::civil_from_days(seconds.div_euclid(86400));
+        let second_of_day = seconds.rem_euclid(86400);
+        Ok(Self {
+            year: Y::from_digits(year.try_into().map_err(|_| Error::Range)?)?,
+            month: month.try_into()?,
+            day: day.try_into()?,
+            hour: (second_of_day / 3600).try_into()?,
+            minute: (second_of_day / 60 % 60).try_into()?,
+            second: (second_of_day % 60).try_into()?,
+            nanosecond,
+            timeshift: Timeshift::Utc,
+        })
+    }
+
+    /// Nanoseconds since 1970-01-01T00:00:00 in the local time of the time shift
+    fn local_nanoseconds(&self) -> i128 {
+        let days = calendar::days_from_civil(self.year.value(), self.month.into(), self.day.into());
+        days as i128 * NANOSECONDS_PER_DAY
+            + time_of_day_nanoseconds(self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    fn with_local_nanoseconds(self, nanoseconds: i128) -> Result<Self, Error> {
+        let days = nanoseconds
+            .div_euclid(NANOSECONDS_PER_DAY)
+            .try_into()
+            .map_err(|_| Error::Range)?;
+        let date = LocalDate::<Y>::from_days_since_epoch(days)?;
+        let nanoseconds = nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64;
+        let seconds = nanoseconds / 1_000_000_000;
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: Hour::new(seconds / 3600)?,
+            minute: Minute::new(seconds / 60 % 60)?,
+            second: Second::new(seconds % 60)?,
+            nanosecond: Nanosecond::new(nanoseconds % 1_000_000_000)?,
+            timeshift: self.timeshift,
+        })
+    }
+
+    /// The same point in time at a different time shift
+    pub fn to_timeshift(self, timeshift: Timeshift) -> Result<Self, Error> {
+        let leap_second = u8::from(self.second) == 60;
+        let mut date_time = self;
+        if leap_second {
+            date_time.second = Second::new(59)?;
+        }
+        let shift = (timeshift.seconds_from_east() - self.timeshift.seconds_from_east()) as i128;
+        let mut date_time = Self {
+            timeshift,
+            ..date_time
+                .with_local_nanoseconds(date_time.local_nanoseconds() + shift * 1_000_000_000)?
+        };
+        if leap_second {
+            date_time.second = Second::new(60)?;
+        }
+        Ok(date_time)
+    }
+
+    /// Add a duration as defined by ISO 8601 and XML Schema
+    ///
+    /// The years and months are added first. When the day does not exist in
+    /// the resulting month, the last day of that month is used, so
+    /// 2023-01-31 plus `P1M` is 2023-02-28. Then the weeks, days and time are
+    /// added, counting a day as 86400 seconds. A negative duration subtracts
+    /// in the same order. The time shift does not change. A leap second counts
+    /// as the first second of the next minute.
+    ///
+    /// Fails with [`Error::CalendarDependent`] for a fraction of a year or
+    /// month, and with [`Error::SubNanosecond`] for a fraction that can't be
+    /// added exactly.
+    pub fn checked_add_duration(self, duration: &Duration) -> Result<Self, Error> {
+        let date_time = self.normalize_end_of_day()?;
+        let sign = if duration.is_negative() { -1 } else { 1 };
+        let months = i64::try_from(duration.calendar_months()?).map_err(|_| Error::Range)?;
+        let date = LocalDate::new(date_time.year, date_time.month, date_time.day)
+            .checked_add_months(sign * months, MonthOverflow::Clamp)?;
+        let time = time_of_day_nanoseconds(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            date_time.nanosecond,
+        ) + sign as i128 * duration.nominal_nanoseconds()?;
+        let days = time.div_euclid(NANOSECONDS_PER_DAY);
+        let date = date.checked_add_days(days.try_into().map_err(|_| Error::Range)?)?;
+        date_time.with_local_nanoseconds(
+            date.days_since_epoch() as i128 * NANOSECONDS_PER_DAY
+                + time.rem_euclid(NANOSECONDS_PER_DAY),
+        )
+    }
+
+    /// The calendar duration from `other` to this date-time
+    ///
+    /// The result uses the largest units possible, so that adding it to
+    /// `other` with [`PreciseShiftedDateTime::checked_add_duration`] gives
+    /// this date-time. `other` is first moved to the time shift of this
+    /// date-time. The duration is negative when `other` is later than this
+    /// date-time.
+    pub fn since(self, other: Self) -> Result<Duration, Error> {
+        let end = self.normalize_end_of_day()?;
+        let start = other.normalize_end_of_day()?.to_timeshift(end.timeshift)?;
+        let end_nanoseconds = end.local_nanoseconds();
+        let sign = if end_nanoseconds < start.local_nanoseconds() {
+            -1
+        } else {
+            1
+        };
+
+        let month_index = |year: i32, month: Month| year as i64 * 12 + i64::from(month);
+        let mut months = sign
+            * (month_index(end.year.value(), end.month)
+                - month_index(start.year.value(), start.month));
+        let anchor = loop {
+            let date = LocalDate::new(start.year, start.month, start.day)
+                .checked_add_months(sign * months, MonthOverflow::Clamp)?;
+            let anchor = Self {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+                ..start
+            };
+            if months <= 0 || sign as i128 * (end_nanoseconds - anchor.local_nanoseconds()) >= 0 {
+                break anchor;
+            }
+            months -= 1;
+        };
+        let months = months.max(0) as u64;
+
+        let remaining = (end_nanoseconds - anchor.local_nanoseconds()).unsigned_abs();
+        Duration::from_nanoseconds(sign < 0, remaining)?
+            .with_years(YearDuration::new(months / 12))?
+            .with_months(MonthDuration::new(months % 12))
+    }
+}
+
+/// Adds a duration, see [`PreciseShiftedDateTime::checked_add_duration`]
+///
+/// # Panics
+///
+/// Panics when the result does not fit in the year type.
+impl<Y: YearDigits> Add<Duration> for PreciseShiftedDateTime<Y> {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add_duration(&rhs)
+            .expect("overflow when adding duration to date-time")
+    }
+}
+
+impl<Y> fmt::Display for PreciseShiftedDateTime<Y>
+where
+    Year<Y>: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let ns_string = format!("{:0>9}", self.nanosecond);
         let ns = if self.nanosecond == Nanosecond::new(0).unwrap() {
@@ -526,7 +1496,7 @@ where
     fn try_from(
         (year, month, day, hour, minute, second, nanosecond, timeshift): (Y, Mo, D, H, Mi, S, N, T),
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
+        let date_time = Self {
             year: year.try_into()?,
             month: month.try_into()?,
             day: day.try_into()?,
@@ -535,63 +1505,224 @@ where
             second: second.try_into()?,
             nanosecond: nanosecond.try_into()?,
             timeshift: timeshift.try_into()?,
-        })
+        };
+        check_time(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            date_time.nanosecond,
+            Some(date_time.timeshift),
+        )?;
+        Ok(date_time)
     }
 }
 
 #[cfg(feature = "chrono")]
 impl From<PreciseShiftedDateTime> for DateTime<FixedOffset> {
     fn from(val: PreciseShiftedDateTime) -> Self {
-        DateTime::from_local(
-            NaiveDateTime::new(
-                NaiveDate::from_ymd_opt(val.year.into(), val.month.into(), val.day.into())
-                    .expect("internal values are already range checked"),
-                NaiveTime::from_hms_nano_opt(
-                    val.hour.into(),
-                    val.minute.into(),
-                    val.second.into(),
-                    val.nanosecond.into(),
-                )
-                .expect("internal values are already range checked"),
-            ),
-            FixedOffset::east_opt(val.timeshift.seconds_from_east())
-                .expect("internal values are already range checked"),
-        )
+        let date_time = to_chrono_date_time(
+            val.year,
+            val.month,
+            val.day,
+            val.hour,
+            val.minute,
+            val.second,
+            val.nanosecond,
+        );
+        to_chrono_fixed_offset(date_time, val.timeshift)
+            .expect("internal values are already range checked")
     }
 }
 
+/// Fails for years outside of chrono's range
 #[cfg(feature = "chrono")]
-impl TryInto<DateTime<Utc>> for PreciseShiftedDateTime {
-    type Error = ();
-
-    fn try_into(self) -> Result<DateTime<Utc>, Self::Error> {
-        match self.timeshift {
-            Timeshift::Utc => Ok(DateTime::<Utc>::from_local(
-                NaiveDateTime::new(
-                    NaiveDate::from_ymd_opt(self.year.into(), self.month.into(), self.day.into())
-                        .expect("internal values are already range checked"),
-                    NaiveTime::from_hms_nano_opt(
-                        self.hour.into(),
-                        self.minute.into(),
-                        self.second.into(),
-                        self.nanosecond.into(),
-                    )
-                    .expect("internal values are already range checked"),
-                ),
-                Utc,
-            )),
-            Timeshift::Offset {
-                non_negative: _,
-                hours: _,
-                minutes: _,
-            } => Err(()),
+impl<const N: usize> TryFrom<PreciseShiftedDateTime<ExtendedYear<N>>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(value: PreciseShiftedDateTime<ExtendedYear<N>>) -> Result<Self, Self::Error> {
+        let date_time = to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        );
+        to_chrono_fixed_offset(date_time, value.timeshift).ok_or(Error::Range)
+    }
+}
+
+/// Fails with [`Error::NotUtc`] unless the time shift is [`Timeshift::Utc`]
+#[cfg(feature = "chrono")]
+impl<Y> TryFrom<PreciseShiftedDateTime<Y>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(value: PreciseShiftedDateTime<Y>) -> Result<Self, Self::Error> {
+        let date_time = to_chrono_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        );
+        to_chrono_utc(date_time, value.timeshift)
+    }
+}
+
+/// Keeps the offset as the time shift, and turns a chrono leap second into second 60
+///
+/// Fails for offsets with seconds, and for leap seconds outside of the last
+/// minute of the UTC day.
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone, Y: YearDigits> TryFrom<DateTime<Tz>> for PreciseShiftedDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: DateTime<Tz>) -> Result<Self, Self::Error> {
+        let timeshift = Timeshift::try_from(value.offset().fix())?;
+        let local = value.naive_local();
+        let date = from_chrono_date(local.date())?;
+        let time = from_chrono_time(local.time());
+        check_time(
+            time.hour,
+            time.minute,
+            time.second,
+            time.nanosecond,
+            Some(timeshift),
+        )?;
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+            timeshift,
+        })
+    }
+}
+
+/// `24:00:00` becomes `00:00:00` of the next day, leap seconds fail
+#[cfg(feature = "time")]
+impl<Y> TryFrom<PreciseShiftedDateTime<Y>> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(value: PreciseShiftedDateTime<Y>) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::try_from(value.timeshift)?;
+        Ok(to_time_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        )?
+        .assume_offset(offset))
+    }
+}
+
+/// Fails for offsets with seconds, which [`Timeshift`] does not have
+#[cfg(feature = "time")]
+impl<Y: YearDigits> TryFrom<OffsetDateTime> for PreciseShiftedDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        let date = from_time_date(value.date())?;
+        let time = from_time_time(value.time());
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+            timeshift: value.offset().try_into()?,
+        })
+    }
+}
+
+/// The instant of the date-time, failing for leap seconds and instants outside of jiff's range
+#[cfg(feature = "jiff")]
+impl<Y> TryFrom<PreciseShiftedDateTime<Y>> for Timestamp {
+    type Error = Error;
+
+    fn try_from(value: PreciseShiftedDateTime<Y>) -> Result<Self, Self::Error> {
+        let offset = jiff::tz::Offset::try_from(value.timeshift)?;
+        let date_time = to_jiff_date_time(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.nanosecond,
+        )?;
+        offset.to_timestamp(date_time).map_err(|_| Error::Range)
+    }
+}
+
+/// The date-time in UTC
+#[cfg(feature = "jiff")]
+impl<Y: YearDigits> TryFrom<Timestamp> for PreciseShiftedDateTime<Y> {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        let nanoseconds = value.as_nanosecond();
+        let seconds = nanoseconds.div_euclid(1_000_000_000) as i64;
+        let nanosecond = Nanosecond::new(nanoseconds.rem_euclid(1_000_000_000) as u64)?;
+        Self::from_unix_timestamp(seconds, nanosecond)
+    }
+}
+
+/// A date-time with an optional fraction and an optional offset
+///
+/// Returned when the input decides which of the date-time types it is, like
+/// `2023-09-17T09:08:58` and `2023-09-17T09:08:58.5Z` in the same feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyDateTime<Y = SimpleYear> {
+    Local(LocalDateTime<Y>),
+    PreciseLocal(PreciseLocalDateTime<Y>),
+    Shifted(ShiftedDateTime<Y>),
+    PreciseShifted(PreciseShiftedDateTime<Y>),
+}
+
+impl<Y> AnyDateTime<Y> {
+    /// The offset, or `None` for local date-times
+    pub fn timeshift(&self) -> Option<Timeshift> {
+        match self {
+            AnyDateTime::Local(_) | AnyDateTime::PreciseLocal(_) => None,
+            AnyDateTime::Shifted(date_time) => Some(date_time.timeshift),
+            AnyDateTime::PreciseShifted(date_time) => Some(date_time.timeshift),
+        }
+    }
+}
+
+impl<Y> fmt::Display for AnyDateTime<Y>
+where
+    Year<Y>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyDateTime::Local(date_time) => write!(f, "{}", date_time),
+            AnyDateTime::PreciseLocal(date_time) => write!(f, "{}", date_time),
+            AnyDateTime::Shifted(date_time) => write!(f, "{}", date_time),
+            AnyDateTime::PreciseShifted(date_time) => write!(f, "{}", date_time),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{LocalDate, PreciseLocalTime, PreciseShiftedDateTime};
+    use super::{LocalDate, LocalTime, MonthOverflow, PreciseLocalTime, PreciseShiftedDateTime};
+    use crate::{
+        components::{Day, Error, ExtendedYear, Month, Timeshift, Weekday, Year},
+        duration::Duration,
+    };
 
     #[test]
     fn test_try_from_tuple() {
@@ -617,4 +1748,416 @@ mod tests {
             .unwrap();
         assert_eq!(format!("{}", dt), "2023-04-09T21:22:02.1234-12:02")
     }
+
+    #[test]
+    fn test_calendar_accessors() {
+        let date: LocalDate = LocalDate::try_from((2023, 9, 17)).unwrap();
+        assert_eq!(date.weekday(), Weekday::Sunday);
+        assert_eq!(date.day_of_year(), 260);
+        assert_eq!(date.days_in_month(), 30);
+        assert_eq!(u8::from(date.iso_week()), 37);
+
+        let date: LocalDate = LocalDate::try_from((2024, 12, 31)).unwrap();
+        assert_eq!(date.day_of_year(), 366);
+        assert_eq!(u8::from(date.iso_week()), 1);
+        assert_eq!(i32::from(date.iso_week_year().unwrap()), 2025);
+
+        let date: LocalDate = LocalDate::try_from((2021, 1, 3)).unwrap();
+        assert_eq!(u8::from(date.iso_week()), 53);
+        assert_eq!(i32::from(date.iso_week_year().unwrap()), 2020);
+
+        let date: LocalDate = LocalDate::try_from((0, 1, 1)).unwrap();
+        assert_eq!(date.weekday(), Weekday::Saturday);
+        assert!(date.iso_week_year().is_err());
+        let date = LocalDate::<ExtendedYear<6>>::new(
+            Year::new_extended(0).unwrap(),
+            Month::new(1).unwrap(),
+            Day::new(1).unwrap(),
+        );
+        assert_eq!(date.iso_week_year().unwrap().value(), -1);
+        assert_eq!(u8::from(date.iso_week()), 52);
+    }
+
+    #[test]
+    fn test_add_days() {
+        let date: LocalDate = LocalDate::try_from((2023, 12, 31)).unwrap();
+        assert_eq!(
+            format!("{}", date.checked_add_days(1).unwrap()),
+            "2024-01-01"
+        );
+        assert_eq!(
+            format!("{}", date.checked_add_days(60).unwrap()),
+            "2024-02-29"
+        );
+        assert_eq!(
+            format!("{}", date.checked_sub_days(365).unwrap()),
+            "2022-12-31"
+        );
+        let date: LocalDate = LocalDate::try_from((9999, 12, 31)).unwrap();
+        assert!(date.checked_add_days(1).is_err());
+        let date = LocalDate::<ExtendedYear<6>>::new(
+            Year::new_extended(9999).unwrap(),
+            Month::new(12).unwrap(),
+            Day::new(31).unwrap(),
+        );
+        assert_eq!(
+            format!("{}", date.checked_add_days(1).unwrap().year),
+            "+010000"
+        );
+        for days in [
+            i64::MAX,
+            i64::MAX - 100_000,
+            i64::MIN,
+            i64::MIN + 100_000,
+            i32::MAX as i64 * 366,
+        ] {
+            assert!(matches!(date.checked_add_days(days), Err(Error::Range)));
+            let date: LocalDate = LocalDate::try_from((2023, 9, 17)).unwrap();
+            assert!(matches!(date.checked_add_days(days), Err(Error::Range)));
+            assert!(matches!(date.checked_sub_days(days), Err(Error::Range)));
+        }
+    }
+
+    #[test]
+    fn test_add_months_and_years() {
+        let date: LocalDate = LocalDate::try_from((2023, 1, 31)).unwrap();
+        let add = |months, overflow| {
+            date.checked_add_months(months, overflow)
+                .map(|date| format!("{}", date))
+        };
+        assert_eq!(add(1, MonthOverflow::Clamp).unwrap(), "2023-02-28");
+        assert_eq!(add(1, MonthOverflow::Spill).unwrap(), "2023-03-03");
+        assert!(matches!(
+            add(1, MonthOverflow::Reject),
+            Err(Error::DayOverflow)
+        ));
+        assert_eq!(add(2, MonthOverflow::Reject).unwrap(), "2023-03-31");
+        assert_eq!(add(-1, MonthOverflow::Reject).unwrap(), "2022-12-31");
+        assert_eq!(add(-2, MonthOverflow::Clamp).unwrap(), "2022-11-30");
+        assert_eq!(add(13, MonthOverflow::Clamp).unwrap(), "2024-02-29");
+        assert!(add(12 * 8000, MonthOverflow::Clamp).is_err());
+
+        let date: LocalDate = LocalDate::try_from((2024, 2, 29)).unwrap();
+        let add = |years, overflow| {
+            date.checked_add_years(years, overflow)
+                .map(|date| format!("{}", date))
+        };
+        assert_eq!(add(1, MonthOverflow::Clamp).unwrap(), "2025-02-28");
+        assert_eq!(add(1, MonthOverflow::Spill).unwrap(), "2025-03-01");
+        assert_eq!(add(4, MonthOverflow::Reject).unwrap(), "2028-02-29");
+        assert_eq!(
+            format!(
+                "{}",
+                date.checked_sub_years(2024, MonthOverflow::Reject).unwrap()
+            ),
+            "0000-02-29"
+        );
+        assert!(date.checked_sub_years(2025, MonthOverflow::Reject).is_err());
+    }
+
+    #[test]
+    fn test_add_duration() {
+        let add = |date_time: &str, duration: &str| {
+            let date_time = crate::parse_rfc3339_datetime(date_time).unwrap();
+            let duration: Duration = duration.parse().unwrap();
+            date_time
+                .checked_add_duration(&duration)
+                .map(|date_time| format!("{}", date_time))
+        };
+        assert_eq!(
+            add("2023-01-31T12:00:00Z", "P1M").unwrap(),
+            "2023-02-28T12:00:00.0Z"
+        );
+        assert_eq!(
+            add("2024-02-29T12:00:00+01:00", "P1Y").unwrap(),
+            "2025-02-28T12:00:00.0+01:00"
+        );
+        assert_eq!(
+            add("2023-12-31T23:30:00Z", "PT45M").unwrap(),
+            "2024-01-01T00:15:00.0Z"
+        );
+        assert_eq!(
+            add("2023-01-31T23:00:00.5Z", "P1M1DT1H0.5S").unwrap(),
+            "2023-03-02T00:00:01.0Z"
+        );
+        assert_eq!(
+            add("2023-09-17T24:00:00Z", "P2W").unwrap(),
+            "2023-10-02T00:00:00.0Z"
+        );
+        assert!(add("9999-12-31T00:00:00Z", "P1D").is_err());
+        assert_eq!(
+            add("2023-03-31T00:00:00Z", "-P1M").unwrap(),
+            "2023-02-28T00:00:00.0Z"
+        );
+        assert_eq!(
+            add("2023-01-01T00:30:00Z", "-PT1H").unwrap(),
+            "2022-12-31T23:30:00.0Z"
+        );
+        assert_eq!(
+            add("2023-01-01T00:00:00Z", "P0.5D").unwrap(),
+            "2023-01-01T12:00:00.0Z"
+        );
+        assert_eq!(
+            add("2023-01-01T00:00:00Z", "PT1.25H").unwrap(),
+            "2023-01-01T01:15:00.0Z"
+        );
+        assert!(matches!(
+            add("2023-01-01T00:00:00Z", "P0.5M"),
+            Err(Error::CalendarDependent)
+        ));
+
+        let date_time = crate::parse_rfc3339_datetime("2023-09-17T09:08:58Z").unwrap();
+        let later = date_time + "PT1S".parse::<Duration>().unwrap();
+        assert_eq!(format!("{}", later), "2023-09-17T09:08:59.0Z");
+    }
+
+    #[test]
+    fn test_since() {
+        let since = |end: &str, start: &str| {
+            let end = crate::parse_rfc3339_datetime(end).unwrap();
+            let start = crate::parse_rfc3339_datetime(start).unwrap();
+            let duration = end.since(start)?;
+            let reached = start
+                .to_timeshift(end.timeshift)?
+                .checked_add_duration(&duration)?;
+            assert_eq!(reached, end.normalize_end_of_day()?);
+            Ok::<_, Error>(format!("{}", duration))
+        };
+        assert_eq!(
+            since("2023-02-28T00:00:00Z", "2023-01-31T00:00:00Z").unwrap(),
+            "P1M"
+        );
+        assert_eq!(
+            since("2023-03-01T00:00:00Z", "2023-01-31T00:00:00Z").unwrap(),
+            "P1M1D"
+        );
+        assert_eq!(
+            since("2024-09-17T09:08:58.5Z", "2023-09-17T11:08:58+02:00").unwrap(),
+            "P1YT0.5S"
+        );
+        assert_eq!(
+            since("2023-09-17T08:00:00Z", "2023-08-17T09:00:00Z").unwrap(),
+            "P30DT23H"
+        );
+        assert_eq!(
+            since("2023-09-17T09:00:00Z", "2023-09-17T09:00:00Z").unwrap(),
+            "PT0S"
+        );
+        assert_eq!(
+            since("2023-09-17T09:00:00Z", "2023-09-17T09:00:01Z").unwrap(),
+            "-PT1S"
+        );
+        assert_eq!(
+            since("2023-02-28T00:00:00Z", "2023-03-31T00:00:00Z").unwrap(),
+            "-P1M"
+        );
+        assert_eq!(
+            since("2023-01-31T00:00:00Z", "2023-03-01T00:00:00Z").unwrap(),
+            "-P1M1D"
+        );
+    }
+
+    #[test]
+    fn test_to_timeshift() {
+        let date_time = crate::parse_rfc3339_datetime("2016-12-31T23:59:60.25Z").unwrap();
+        let shifted = date_time
+            .to_timeshift(Timeshift::try_from((-5, 30)).unwrap())
+            .unwrap();
+        assert_eq!(format!("{}", shifted), "2016-12-31T18:29:60.25-05:30");
+    }
+
+    #[test]
+    fn test_end_of_day() {
+        assert!(LocalTime::try_from((24, 0, 0)).is_ok());
+        assert!(LocalTime::try_from((24, 1, 0)).is_err());
+        assert!(PreciseLocalTime::try_from((24, 0, 0, 1)).is_err());
+
+        let dt = PreciseShiftedDateTime::try_from((2023, 12, 31, 24, 0, 0, 0, (1, 0)))
+            .unwrap()
+            .normalize_end_of_day()
+            .unwrap();
+        assert_eq!(format!("{}", dt), "2024-01-01T00:00:00.0+01:00");
+        let dt = PreciseShiftedDateTime::try_from((2024, 2, 28, 24, 0, 0, 0, (1, 0)))
+            .unwrap()
+            .normalize_end_of_day()
+            .unwrap();
+        assert_eq!(format!("{}", dt), "2024-02-29T00:00:00.0+01:00");
+        let dt = PreciseShiftedDateTime::try_from((9999, 12, 31, 24, 0, 0, 0, (0, 0))).unwrap();
+        assert!(dt.normalize_end_of_day().is_err());
+    }
+
+    #[test]
+    fn test_leap_second() {
+        assert!(LocalTime::try_from((12, 30, 60)).is_ok());
+        assert!(PreciseShiftedDateTime::try_from((2016, 12, 31, 23, 59, 60, 0, (0, 0))).is_ok());
+        assert!(PreciseShiftedDateTime::try_from((2016, 12, 31, 18, 29, 60, 0, (-5, 30))).is_ok());
+        assert!(PreciseShiftedDateTime::try_from((2016, 12, 31, 23, 59, 60, 0, (1, 0))).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        use crate::components::SimpleYear;
+        use time::{OffsetDateTime, PrimitiveDateTime};
+
+        let dt: PreciseShiftedDateTime = "2023-09-17T09:08:58.5+02:00".parse().unwrap();
+        let offset = OffsetDateTime::try_from(dt).unwrap();
+        assert_eq!(offset.unix_timestamp(), dt.unix_timestamp());
+        assert_eq!(offset.nanosecond(), 500_000_000);
+        assert_eq!(PreciseShiftedDateTime::try_from(offset).unwrap(), dt);
+
+        let end_of_day: PreciseShiftedDateTime = "2023-12-31T24:00:00Z".parse().unwrap();
+        let offset = OffsetDateTime::try_from(end_of_day).unwrap();
+        assert_eq!(offset.date().year(), 2024);
+        let leap: PreciseShiftedDateTime = "2016-12-31T23:59:60Z".parse().unwrap();
+        assert!(matches!(
+            OffsetDateTime::try_from(leap),
+            Err(Error::LeapSecond)
+        ));
+        let local: crate::PreciseLocalDateTime = "2023-12-31T24:00:00".parse().unwrap();
+        let local = PrimitiveDateTime::try_from(local).unwrap();
+        assert_eq!(local.date().year(), 2024);
+        assert_eq!(local.time(), time::Time::MIDNIGHT);
+        let time: PreciseLocalTime = "24:00:00".parse().unwrap();
+        assert!(matches!(time::Time::try_from(time), Err(Error::EndOfDay)));
+
+        let date = time::Date::from_calendar_date(-44, time::Month::March, 15).unwrap();
+        assert!(LocalDate::<SimpleYear>::try_from(date).is_err());
+        let date = LocalDate::<ExtendedYear<6>>::try_from(date).unwrap();
+        assert_eq!(format!("{}", date), "-000044-03-15");
+        let date: LocalDate<ExtendedYear<6>> = "+012023-01-01".parse().unwrap();
+        assert!(time::Date::try_from(date).is_err());
+
+        let local = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2023, time::Month::September, 17).unwrap(),
+            time::Time::from_hms(9, 8, 58).unwrap(),
+        );
+        let local = crate::PreciseLocalDateTime::<SimpleYear>::try_from(local).unwrap();
+        assert_eq!(format!("{}", local), "2023-09-17T09:08:58.0");
+
+        let offset = time::UtcOffset::from_hms(5, 30, 1).unwrap();
+        assert!(Timeshift::try_from(offset).is_err());
+        let offset = time::UtcOffset::from_hms(-5, -30, 0).unwrap();
+        assert_eq!(
+            format!("{}", Timeshift::try_from(offset).unwrap()),
+            "-05:30"
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff() {
+        use crate::{components::SimpleYear, PreciseLocalDateTime};
+        use jiff::{civil, Timestamp};
+
+        let dt: PreciseShiftedDateTime = "2023-09-17T09:08:58.5+02:00".parse().unwrap();
+        let timestamp = Timestamp::try_from(dt).unwrap();
+        assert_eq!(timestamp.as_second(), dt.unix_timestamp());
+        assert_eq!(
+            format!(
+                "{}",
+                PreciseShiftedDateTime::<SimpleYear>::try_from(timestamp).unwrap()
+            ),
+            "2023-09-17T07:08:58.5Z"
+        );
+        let timestamp = Timestamp::new(-1, -500_000_000).unwrap();
+        assert_eq!(
+            format!(
+                "{}",
+                PreciseShiftedDateTime::<SimpleYear>::try_from(timestamp).unwrap()
+            ),
+            "1969-12-31T23:59:58.5Z"
+        );
+        let leap: PreciseShiftedDateTime = "2016-12-31T23:59:60Z".parse().unwrap();
+        assert!(matches!(Timestamp::try_from(leap), Err(Error::LeapSecond)));
+
+        let local: PreciseLocalDateTime = "2023-12-31T24:00:00".parse().unwrap();
+        let local = civil::DateTime::try_from(local).unwrap();
+        assert_eq!(local, civil::date(2024, 1, 1).at(0, 0, 0, 0));
+        let local = PreciseLocalDateTime::<SimpleYear>::try_from(local).unwrap();
+        assert_eq!(format!("{}", local), "2024-01-01T00:00:00.0");
+        let time: PreciseLocalTime = "23:59:60".parse().unwrap();
+        assert!(matches!(
+            civil::Time::try_from(time),
+            Err(Error::LeapSecond)
+        ));
+        assert_eq!(
+            PreciseLocalTime::from(civil::time(9, 8, 58, 5)),
+            "09:08:58.000000005".parse().unwrap()
+        );
+
+        let date = LocalDate::<ExtendedYear<6>>::try_from(civil::date(-44, 3, 15)).unwrap();
+        assert_eq!(format!("{}", date), "-000044-03-15");
+        assert!(LocalDate::<SimpleYear>::try_from(civil::date(-44, 3, 15)).is_err());
+        let date: LocalDate<ExtendedYear<6>> = "+010000-01-01".parse().unwrap();
+        assert!(matches!(civil::Date::try_from(date), Err(Error::Range)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_leap_second_and_end_of_day() {
+        use chrono::{DateTime, FixedOffset, NaiveTime, Timelike, Utc};
+
+        let dt = PreciseShiftedDateTime::try_from((1990, 12, 31, 15, 59, 60, 0, (-8, 0))).unwrap();
+        let chrono_dt: DateTime<FixedOffset> = dt.into();
+        assert_eq!(chrono_dt.second(), 59);
+        assert_eq!(chrono_dt.nanosecond(), 1_000_000_000);
+        assert_eq!(
+            chrono_dt.with_timezone(&Utc).to_rfc3339(),
+            "1990-12-31T23:59:60+00:00"
+        );
+
+        let dt = PreciseShiftedDateTime::try_from((2023, 12, 31, 24, 0, 0, 0, (0, 0))).unwrap();
+        let chrono_dt: DateTime<FixedOffset> = dt.into();
+        assert_eq!(chrono_dt.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+
+        let time: NaiveTime = LocalTime::try_from((24, 0, 0)).unwrap().into();
+        assert_eq!(time, NaiveTime::MIN);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_from() {
+        use crate::components::SimpleYear;
+        use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+
+        let chrono_dt = FixedOffset::east_opt(-(5 * 3600 + 30 * 60))
+            .unwrap()
+            .with_ymd_and_hms(2023, 9, 17, 9, 8, 58)
+            .unwrap();
+        let dt = PreciseShiftedDateTime::<SimpleYear>::try_from(chrono_dt).unwrap();
+        assert_eq!(format!("{}", dt), "2023-09-17T09:08:58.0-05:30");
+        assert_eq!(DateTime::<FixedOffset>::from(dt), chrono_dt);
+        assert!(matches!(DateTime::<Utc>::try_from(dt), Err(Error::NotUtc)));
+
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        let dt = PreciseShiftedDateTime::<SimpleYear>::try_from(leap).unwrap();
+        assert_eq!(format!("{}", dt), "2016-12-31T23:59:60.5Z");
+        assert_eq!(DateTime::<Utc>::try_from(dt).unwrap(), leap);
+        let time = NaiveTime::from_hms_nano_opt(12, 0, 59, 1_000_000_000).unwrap();
+        assert_eq!(format!("{}", PreciseLocalTime::from(time)), "12:00:60.0");
+        let not_leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_time(time)
+            .and_utc();
+        assert!(matches!(
+            PreciseShiftedDateTime::<SimpleYear>::try_from(not_leap),
+            Err(Error::LeapSecond)
+        ));
+
+        let date = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert!(LocalDate::<SimpleYear>::try_from(date).is_err());
+        let date = LocalDate::<ExtendedYear<6>>::try_from(date).unwrap();
+        assert_eq!(format!("{}", date), "-000044-03-15");
+        assert_eq!(
+            NaiveDate::try_from(date).unwrap(),
+            NaiveDate::from_ymd_opt(-44, 3, 15).unwrap()
+        );
+        let date: LocalDate<ExtendedYear<6>> = "+999999-01-01".parse().unwrap();
+        assert!(matches!(NaiveDate::try_from(date), Err(Error::Range)));
+    }
 }
@@ -1,19 +1,82 @@
-use core::{fmt, num};
+use core::{fmt, num, str};
+
+use crate::calendar;
 
 #[derive(Debug)]
 pub enum Error {
     Range,
     ParseInt(num::ParseIntError),
     Parse,
+    /// Hour 24 was used for something other than `24:00:00`
+    EndOfDay,
+    /// Second 60 was used outside of the last minute of the UTC day
+    LeapSecond,
+    /// The day does not exist in the month
+    DayOverflow,
+    /// A negative value where only non-negative values are possible
+    Negative,
+    /// A length in years or months, which depends on the calendar
+    CalendarDependent,
+    /// A fraction smaller than a nanosecond
+    SubNanosecond,
+    /// A time shift other than UTC where only UTC is possible
+    NotUtc,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Range => write!(f, "value out of range"),
+            Error::ParseInt(e) => write!(f, "invalid number: {}", e),
+            Error::Parse => write!(f, "invalid format"),
+            Error::EndOfDay => write!(f, "hour 24 is only allowed as 24:00:00"),
+            Error::LeapSecond => {
+                write!(
+                    f,
+                    "second 60 is only allowed in the last minute of the UTC day"
+                )
+            }
+            Error::DayOverflow => write!(f, "day does not exist in the month"),
+            Error::Negative => write!(f, "value is negative"),
+            Error::CalendarDependent => {
+                write!(f, "length of years and months depends on the calendar")
+            }
+            Error::SubNanosecond => write!(f, "fraction is smaller than a nanosecond"),
+            Error::NotUtc => write!(f, "time shift is not UTC"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ParseInt(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parse exactly `n` ASCII digits
+fn parse_digits(s: &str, n: usize) -> Result<u64, Error> {
+    if s.len() != n || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::Parse);
+    }
+    s.parse().map_err(Error::ParseInt)
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SimpleYear;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ExtendedYear<const N: usize>;
 
-pub trait YearDigits {
+pub trait YearDigits: Copy {
     fn digits() -> usize;
-    fn from_digits(digits: i32) -> Result<Year<Self>, Error> where Self: Sized;
+    /// Whether the year is written with a leading `+` or `-`
+    fn signed() -> bool {
+        false
+    }
+    fn from_digits(digits: i32) -> Result<Year<Self>, Error>
+    where
+        Self: Sized;
 }
 
 impl YearDigits for SimpleYear {
@@ -29,6 +92,9 @@ impl<const N: usize> YearDigits for ExtendedYear<N> {
     fn digits() -> usize {
         N
     }
+    fn signed() -> bool {
+        true
+    }
     fn from_digits(digits: i32) -> Result<Year<Self>, Error> {
         Year::new_extended(digits)
     }
@@ -53,7 +119,7 @@ impl<const N: usize> Year<ExtendedYear<N>> {
 
 impl Year<SimpleYear> {
     pub fn new(year: i32) -> Result<Self, Error> {
-        if year < 0 || year > 9999 {
+        if !(0..=9999).contains(&year) {
             return Err(Error::Range);
         }
 
@@ -61,9 +127,25 @@ impl Year<SimpleYear> {
     }
 }
 
+impl<Y> Year<Y> {
+    pub(crate) fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// Whether the year has a 29th of February in the proleptic Gregorian calendar
+    pub fn is_leap_year(&self) -> bool {
+        calendar::is_leap_year(self.0)
+    }
+
+    /// Number of days in a month of this year
+    pub fn days_in_month(&self, month: Month) -> u8 {
+        calendar::days_in_month(self.0, month.0)
+    }
+}
+
 #[cfg(test)]
 mod year_test {
-    use super::{ExtendedYear, Year};
+    use super::{ExtendedYear, Month, Second, Timeshift, Year};
 
     #[test]
     fn test_year_4_digits() {
@@ -84,6 +166,46 @@ mod year_test {
         );
     }
 
+    #[test]
+    fn test_leap_second() {
+        assert!(Second::new(60).is_ok());
+        assert!(Second::new(61).is_err());
+    }
+
+    #[test]
+    fn test_leap_years() {
+        assert!(Year::new(2000).unwrap().is_leap_year());
+        assert!(Year::new(2024).unwrap().is_leap_year());
+        assert!(!Year::new(1900).unwrap().is_leap_year());
+        assert!(!Year::new(2023).unwrap().is_leap_year());
+        assert!(Year::<ExtendedYear<6>>::new_extended(-4)
+            .unwrap()
+            .is_leap_year());
+        assert!(!Year::<ExtendedYear<6>>::new_extended(-100)
+            .unwrap()
+            .is_leap_year());
+        let february = Month::new(2).unwrap();
+        assert_eq!(Year::new(2024).unwrap().days_in_month(february), 29);
+        assert_eq!(Year::new(2023).unwrap().days_in_month(february), 28);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0044".parse::<Year>().unwrap(), Year::new(44).unwrap());
+        assert_eq!(
+            "-000044".parse::<Year<ExtendedYear<6>>>().unwrap(),
+            Year::new_extended(-44).unwrap()
+        );
+        assert!("44".parse::<Year>().is_err());
+        assert!("+2023".parse::<Year>().is_err());
+        assert!("13".parse::<Month>().is_err());
+        assert_eq!("60".parse::<Second>().unwrap(), Second::new(60).unwrap());
+        for s in ["Z", "+05:30", "-00:00"] {
+            assert_eq!(format!("{}", s.parse::<Timeshift>().unwrap()), s);
+        }
+        assert!("05:30".parse::<Timeshift>().is_err());
+    }
+
     #[test]
     fn test_big_years() {
         assert!(Year::<ExtendedYear<6>>::new_extended(100000).is_ok());
@@ -112,6 +234,22 @@ impl<const N: usize> fmt::Display for Year<ExtendedYear<N>> {
     }
 }
 
+/// Parses the year as it is displayed, with a sign for extended years
+impl<Y: YearDigits> str::FromStr for Year<Y> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, digits) = match (Y::signed(), s.as_bytes().first()) {
+            (false, _) => (1, s),
+            (true, Some(b'+')) => (1, &s[1..]),
+            (true, Some(b'-')) => (-1, &s[1..]),
+            (true, _) => return Err(Error::Parse),
+        };
+        let year = parse_digits(digits, Y::digits())?;
+        Y::from_digits(sign * i32::try_from(year).map_err(|_| Error::Range)?)
+    }
+}
+
 macro_rules! impl_try_from {
     ($primitive:ty, $structtype:ident) => {
         impl TryFrom<$primitive> for $structtype {
@@ -176,6 +314,14 @@ impl fmt::Display for Month {
     }
 }
 
+impl str::FromStr for Month {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Month::new(parse_digits(s, 2)?)
+    }
+}
+
 macro_rules! impl_try_from {
     ($primitive:ty, $structtype:ident) => {
         impl TryFrom<$primitive> for $structtype {
@@ -214,6 +360,9 @@ pub struct Week(u8);
 
 impl Week {
     pub fn new(week: u64) -> Result<Self, Error> {
+        if week == 0 {
+            return Err(Error::Range);
+        }
         if week > 53 {
             return Err(Error::Range);
         }
@@ -227,6 +376,14 @@ impl fmt::Display for Week {
     }
 }
 
+impl str::FromStr for Week {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Week::new(parse_digits(s.strip_prefix('W').ok_or(Error::Parse)?, 2)?)
+    }
+}
+
 impl_try_from!(u8, Week);
 impl_try_from!(u16, Week);
 impl_try_from!(u32, Week);
@@ -244,6 +401,134 @@ impl_into!(i16, Week);
 impl_into!(i32, Week);
 impl_into!(i64, Week);
 
+/// Day of the week, numbered from Monday (1) to Sunday (7) as in ISO 8601
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn new(weekday: u64) -> Result<Self, Error> {
+        match weekday {
+            1 => Ok(Self::Monday),
+            2 => Ok(Self::Tuesday),
+            3 => Ok(Self::Wednesday),
+            4 => Ok(Self::Thursday),
+            5 => Ok(Self::Friday),
+            6 => Ok(Self::Saturday),
+            7 => Ok(Self::Sunday),
+            _ => Err(Error::Range),
+        }
+    }
+}
+
+/// English names of the days of the week, from Monday
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Writes the English name, like `Monday`
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(WEEKDAY_NAMES[*self as usize - 1])
+    }
+}
+
+/// Parses the English name as it is displayed
+impl str::FromStr for Weekday {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = WEEKDAY_NAMES
+            .iter()
+            .position(|&name| name == s)
+            .ok_or(Error::Parse)?;
+        Weekday::new(index as u64 + 1)
+    }
+}
+
+impl_try_from!(u8, Weekday);
+impl_try_from!(u16, Weekday);
+impl_try_from!(u32, Weekday);
+impl_try_from!(u64, Weekday);
+impl_try_from!(i8, Weekday);
+impl_try_from!(i16, Weekday);
+impl_try_from!(i32, Weekday);
+impl_try_from!(i64, Weekday);
+
+macro_rules! impl_into_weekday {
+    ($primitive:ty) => {
+        impl From<Weekday> for $primitive {
+            fn from(value: Weekday) -> $primitive {
+                value as $primitive
+            }
+        }
+    };
+}
+
+impl_into_weekday!(u8);
+impl_into_weekday!(i8);
+impl_into_weekday!(u16);
+impl_into_weekday!(u32);
+impl_into_weekday!(u64);
+impl_into_weekday!(i16);
+impl_into_weekday!(i32);
+impl_into_weekday!(i64);
+
+#[cfg(test)]
+mod week_test {
+    use super::Week;
+
+    #[test]
+    fn test_week_range() {
+        assert!(Week::new(0).is_err());
+        assert!(Week::new(1).is_ok());
+        assert!(Week::new(53).is_ok());
+        assert!(Week::new(54).is_err());
+        assert!("W00".parse::<Week>().is_err());
+        assert_eq!("W01".parse::<Week>().unwrap().to_string(), "W01");
+    }
+}
+
+#[cfg(test)]
+mod weekday_test {
+    use super::Weekday;
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(Weekday::new(1).unwrap(), Weekday::Monday);
+        assert_eq!(Weekday::try_from(7i8).unwrap(), Weekday::Sunday);
+        assert!(Weekday::new(0).is_err());
+        assert!(Weekday::new(8).is_err());
+        assert_eq!(i8::from(Weekday::Wednesday), 3);
+        assert_eq!(u64::from(Weekday::Sunday), 7);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Weekday::Monday.to_string(), "Monday");
+        assert_eq!(format!("{:<9}|", Weekday::Friday), "Friday   |");
+        for weekday in 1..=7 {
+            let weekday = Weekday::new(weekday).unwrap();
+            assert_eq!(weekday.to_string().parse::<Weekday>().unwrap(), weekday);
+        }
+        assert!("1".parse::<Weekday>().is_err());
+        assert!("monday".parse::<Weekday>().is_err());
+    }
+}
+
 /// Day of the month (1-31)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Day(u8);
@@ -266,6 +551,14 @@ impl fmt::Display for Day {
     }
 }
 
+impl str::FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Day::new(parse_digits(s, 2)?)
+    }
+}
+
 macro_rules! impl_try_from {
     ($primitive:ty, $structtype:ident) => {
         impl TryFrom<$primitive> for $structtype {
@@ -298,7 +591,16 @@ impl_into!(i16, Day);
 impl_into!(i32, Day);
 impl_into!(i64, Day);
 
-/// Hours (0-60)
+/// Hours (0-24)
+///
+/// Hour 24 is only meaningful as `24:00:00`, the end of a day. The combined
+/// time types check this when they are parsed or built from tuples.
+///
+/// chrono, `time` and jiff have no hour 24, so conversions to their
+/// date-times turn `24:00:00` into `00:00:00` of the next day. A time without
+/// a date has no next day, so converting `24:00:00` to a `time` or jiff time
+/// fails with [`Error::EndOfDay`]. Only the chrono time conversion, which
+/// can't fail, turns it into `00:00:00`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Hour(u8);
 
@@ -317,6 +619,14 @@ impl fmt::Display for Hour {
     }
 }
 
+impl str::FromStr for Hour {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hour::new(parse_digits(s, 2)?)
+    }
+}
+
 macro_rules! impl_try_from {
     ($primitive:ty, $structtype:ident) => {
         impl TryFrom<$primitive> for $structtype {
@@ -368,6 +678,14 @@ impl fmt::Display for Minute {
     }
 }
 
+impl str::FromStr for Minute {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Minute::new(parse_digits(s, 2)?)
+    }
+}
+
 impl_try_from!(u8, Minute);
 impl_try_from!(u16, Minute);
 impl_try_from!(u32, Minute);
@@ -385,13 +703,21 @@ impl_into!(i16, Minute);
 impl_into!(i32, Minute);
 impl_into!(i64, Minute);
 
-/// Seconds (0-61)
+/// Seconds (0-60)
+///
+/// Second 60 is a positive leap second, which can only occur during the last
+/// minute of a UTC day. The combined time types check this when they are
+/// parsed or built from tuples.
+///
+/// chrono represents a leap second as second 59 with a nanosecond of at least
+/// 1_000_000_000, so conversions to chrono turn `23:59:60.5` into `23:59:59`
+/// with 1_500_000_000 nanoseconds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Second(u8);
 
 impl Second {
     pub fn new(second: u64) -> Result<Second, Error> {
-        if second > 61 {
+        if second > 60 {
             return Err(Error::Range);
         }
         Ok(Second(second as u8))
@@ -404,6 +730,14 @@ impl fmt::Display for Second {
     }
 }
 
+impl str::FromStr for Second {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Second::new(parse_digits(s, 2)?)
+    }
+}
+
 impl_try_from!(u8, Second);
 impl_try_from!(u16, Second);
 impl_try_from!(u32, Second);
@@ -440,6 +774,17 @@ impl fmt::Display for Nanosecond {
     }
 }
 
+impl str::FromStr for Nanosecond {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.len() > 9 {
+            return Err(Error::Parse);
+        }
+        Nanosecond::new(parse_digits(s, s.len())?)
+    }
+}
+
 macro_rules! impl_try_from {
     ($primitive:ty, $structtype:ident) => {
         impl TryFrom<$primitive> for $structtype {
@@ -515,10 +860,23 @@ impl Timeshift {
                 let minutes: i32 = (*minutes).into();
                 let sign = if *non_negative { 1 } else { -1 };
 
-                sign * hours * 3600 + minutes * 60
+                sign * (hours * 3600 + minutes * 60)
             }
         }
     }
+
+    /// An offset, also when it is zero, failing for offsets with seconds
+    pub(crate) fn from_seconds_from_east(seconds: i32) -> Result<Self, Error> {
+        if seconds % 60 != 0 {
+            return Err(Error::Range);
+        }
+        let minutes = seconds.unsigned_abs() / 60;
+        Ok(Timeshift::offset(
+            seconds >= 0,
+            Hour::new((minutes / 60).into())?,
+            Minute::new((minutes % 60).into())?,
+        ))
+    }
 }
 
 impl fmt::Display for Timeshift {
@@ -539,6 +897,105 @@ impl fmt::Display for Timeshift {
     }
 }
 
+impl str::FromStr for Timeshift {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "Z" {
+            return Ok(Timeshift::Utc);
+        }
+        let non_negative = match s.as_bytes().first() {
+            Some(b'+') => true,
+            Some(b'-') => false,
+            _ => return Err(Error::Parse),
+        };
+        let (hours, minutes) = s[1..].split_once(':').ok_or(Error::Parse)?;
+        let (hours, minutes): (Hour, Minute) = (hours.parse()?, minutes.parse()?);
+        // Hour and Minute allow 24 and 60, which no offset has
+        if u8::from(hours) > 23 || u8::from(minutes) > 59 {
+            return Err(Error::Range);
+        }
+        Ok(Timeshift::offset(non_negative, hours, minutes))
+    }
+}
+
+/// Fails when the offset is larger than `time` supports
+#[cfg(feature = "time")]
+impl TryFrom<Timeshift> for time::UtcOffset {
+    type Error = Error;
+
+    fn try_from(value: Timeshift) -> Result<Self, Self::Error> {
+        time::UtcOffset::from_whole_seconds(value.seconds_from_east()).map_err(|_| Error::Range)
+    }
+}
+
+/// A zero offset becomes [`Timeshift::Utc`], offsets with seconds fail
+#[cfg(feature = "time")]
+impl TryFrom<time::UtcOffset> for Timeshift {
+    type Error = Error;
+
+    fn try_from(value: time::UtcOffset) -> Result<Self, Self::Error> {
+        let (hours, minutes, seconds) = value.as_hms();
+        if seconds != 0 {
+            return Err(Error::Range);
+        }
+        if value.is_utc() {
+            return Ok(Timeshift::Utc);
+        }
+        Ok(Timeshift::offset(
+            !value.is_negative(),
+            Hour::new(hours.unsigned_abs().into())?,
+            Minute::new(minutes.unsigned_abs().into())?,
+        ))
+    }
+}
+
+/// Fails when the offset is larger than jiff supports
+#[cfg(feature = "jiff")]
+impl TryFrom<Timeshift> for jiff::tz::Offset {
+    type Error = Error;
+
+    fn try_from(value: Timeshift) -> Result<Self, Self::Error> {
+        jiff::tz::Offset::from_seconds(value.seconds_from_east()).map_err(|_| Error::Range)
+    }
+}
+
+/// A zero offset becomes [`Timeshift::Utc`], offsets with seconds fail
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::tz::Offset> for Timeshift {
+    type Error = Error;
+
+    fn try_from(value: jiff::tz::Offset) -> Result<Self, Self::Error> {
+        match value.seconds() {
+            0 => Ok(Timeshift::Utc),
+            seconds => Timeshift::from_seconds_from_east(seconds),
+        }
+    }
+}
+
+/// Fails for `+24:00` and `-24:00`, which chrono does not support
+#[cfg(feature = "chrono")]
+impl TryFrom<Timeshift> for chrono::FixedOffset {
+    type Error = Error;
+
+    fn try_from(value: Timeshift) -> Result<Self, Self::Error> {
+        chrono::FixedOffset::east_opt(value.seconds_from_east()).ok_or(Error::Range)
+    }
+}
+
+/// A zero offset becomes [`Timeshift::Utc`], offsets with seconds fail
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::FixedOffset> for Timeshift {
+    type Error = Error;
+
+    fn try_from(value: chrono::FixedOffset) -> Result<Self, Self::Error> {
+        match value.local_minus_utc() {
+            0 => Ok(Timeshift::Utc),
+            seconds => Timeshift::from_seconds_from_east(seconds),
+        }
+    }
+}
+
 impl TryFrom<(i32, i32)> for Timeshift {
     type Error = Error;
 
@@ -561,3 +1018,34 @@ impl TryFrom<(i32, i32)> for Timeshift {
         }
     }
 }
+
+#[cfg(test)]
+mod timeshift_test {
+    use super::Timeshift;
+
+    #[test]
+    fn test_seconds_from_east() {
+        assert_eq!(Timeshift::Utc.seconds_from_east(), 0);
+        let shift = Timeshift::try_from((5, 30)).unwrap();
+        assert_eq!(shift.seconds_from_east(), 5 * 3600 + 30 * 60);
+        // The sign applies to the minutes as well as the hours
+        let shift = Timeshift::try_from((-5, 30)).unwrap();
+        assert_eq!(shift.seconds_from_east(), -(5 * 3600 + 30 * 60));
+        let shift = Timeshift::try_from((-0, 30)).unwrap();
+        assert_eq!(shift.seconds_from_east(), 30 * 60);
+        let shift: Timeshift = "-00:30".parse().unwrap();
+        assert_eq!(shift.seconds_from_east(), -30 * 60);
+    }
+
+    #[test]
+    fn test_from_str_range() {
+        assert!("+23:59".parse::<Timeshift>().is_ok());
+        for s in ["+24:00", "-24:00", "+02:60"] {
+            assert!(
+                matches!(s.parse::<Timeshift>(), Err(super::Error::Range)),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -2,10 +2,13 @@
 
 use core::{fmt, str};
 
-use crate::components::Error;
+use crate::{
+    combined::PreciseShiftedDateTime,
+    components::{Error, Nanosecond, YearDigits},
+};
 
 /// An amount of years
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct YearDuration(u64);
 
 impl YearDuration {
@@ -59,7 +62,7 @@ macro_rules! impl_into {
 impl_into!(u64, YearDuration);
 
 /// An amount of months
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MonthDuration(u64);
 
 impl MonthDuration {
@@ -103,7 +106,7 @@ impl_from!(u64, MonthDuration);
 impl_into!(u64, MonthDuration);
 
 /// An amount of weeks
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct WeekDuration(u64);
 
 impl WeekDuration {
@@ -143,7 +146,7 @@ impl_from!(u64, WeekDuration);
 impl_into!(u64, WeekDuration);
 
 /// An amount of days
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DayDuration(u64);
 
 impl DayDuration {
@@ -183,7 +186,7 @@ impl_from!(u64, DayDuration);
 impl_into!(u64, DayDuration);
 
 /// An amount of hours
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct HourDuration(u64);
 
 impl HourDuration {
@@ -223,7 +226,7 @@ impl_from!(u64, HourDuration);
 impl_into!(u64, HourDuration);
 
 /// An amount of minutes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MinuteDuration(u64);
 
 impl MinuteDuration {
@@ -263,7 +266,7 @@ impl_from!(u64, MinuteDuration);
 impl_into!(u64, MinuteDuration);
 
 /// An amount of seconds
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SecondDuration(u64);
 
 impl SecondDuration {
@@ -274,7 +277,7 @@ impl SecondDuration {
 
 impl fmt::Display for SecondDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}M", self.0)
+        write!(f, "{}S", self.0)
     }
 }
 
@@ -282,7 +285,7 @@ impl str::FromStr for SecondDuration {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.strip_suffix('M')
+        s.strip_suffix('S')
             .ok_or(Error::Parse)
             .and_then(|s| s.parse().map_err(Error::ParseInt))
             .map(Self::new)
@@ -301,3 +304,835 @@ impl_from!(u32, SecondDuration);
 impl_from!(u64, SecondDuration);
 
 impl_into!(u64, SecondDuration);
+
+/// The component of a [`Duration`] that a [`Fraction`] belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl Unit {
+    fn designator(self) -> char {
+        match self {
+            Unit::Years => 'Y',
+            Unit::Months | Unit::Minutes => 'M',
+            Unit::Weeks => 'W',
+            Unit::Days => 'D',
+            Unit::Hours => 'H',
+            Unit::Seconds => 'S',
+        }
+    }
+
+    /// Length of the unit in nanoseconds, if it does not depend on the calendar
+    fn nanoseconds(self) -> Option<i128> {
+        match self {
+            Unit::Years | Unit::Months => None,
+            Unit::Weeks => Some(7 * 86_400_000_000_000),
+            Unit::Days => Some(86_400_000_000_000),
+            Unit::Hours => Some(3_600_000_000_000),
+            Unit::Minutes => Some(60_000_000_000),
+            Unit::Seconds => Some(1_000_000_000),
+        }
+    }
+}
+
+/// The exact decimal fraction of the lowest-order component of a [`Duration`]
+///
+/// `PT1.5H` has 1 hour and a fraction of 5 tenths of an hour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fraction {
+    unit: Unit,
+    numerator: u64,
+    digits: u8,
+}
+
+impl Fraction {
+    /// A fraction of `numerator / 10^digits` of a unit, with up to 18 digits
+    pub fn new(unit: Unit, numerator: u64, digits: u8) -> Result<Self, Error> {
+        if digits > 18 || numerator >= 10u64.pow(digits as u32) {
+            return Err(Error::Range);
+        }
+        let (mut numerator, mut digits) = (numerator, digits);
+        while digits > 0 && numerator % 10 == 0 {
+            numerator /= 10;
+            digits -= 1;
+        }
+        Ok(Self {
+            unit,
+            numerator,
+            digits,
+        })
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    /// Number of decimal digits, the fraction is `numerator / 10^digits`
+    pub fn digits(&self) -> u8 {
+        self.digits
+    }
+
+    /// The exact number of nanoseconds in this fraction of its unit
+    fn nanoseconds(&self) -> Result<i128, Error> {
+        let unit = self.unit.nanoseconds().ok_or(Error::CalendarDependent)?;
+        let denominator = 10i128.pow(self.digits as u32);
+        let scaled = self.numerator as i128 * unit;
+        if scaled % denominator != 0 {
+            return Err(Error::SubNanosecond);
+        }
+        Ok(scaled / denominator)
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:0>width$}",
+            self.numerator,
+            width = self.digits as usize
+        )
+    }
+}
+
+/// A duration made up of any of the components above, such as `-P1Y2M3DT4H5M6.5S`
+///
+/// The lowest-order component that is present can have an exact decimal
+/// [`Fraction`], as in `PT1.5H` or `P0.5Y`. Components of a lower order than
+/// the unit of the fraction are always zero.
+///
+/// ```rust
+/// # use datetimeparse::duration::{Duration, Fraction, HourDuration, Unit};
+/// let duration = Duration::new()
+///     .with_hours(HourDuration::new(1))?
+///     .with_fraction(Fraction::new(Unit::Hours, 5, 1)?)?;
+/// assert_eq!((-duration).to_string(), "-PT1.5H");
+/// # Ok::<(), datetimeparse::ComponentError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duration {
+    negative: bool,
+    years: YearDuration,
+    months: MonthDuration,
+    weeks: WeekDuration,
+    days: DayDuration,
+    hours: HourDuration,
+    minutes: MinuteDuration,
+    seconds: SecondDuration,
+    fraction: Option<Fraction>,
+}
+
+impl Duration {
+    /// A zero duration, to add components to with the `with_` methods
+    pub fn new() -> Self {
+        Self {
+            negative: false,
+            years: YearDuration::default(),
+            months: MonthDuration::default(),
+            weeks: WeekDuration::default(),
+            days: DayDuration::default(),
+            hours: HourDuration::default(),
+            minutes: MinuteDuration::default(),
+            seconds: SecondDuration::default(),
+            fraction: None,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn years(&self) -> YearDuration {
+        self.years
+    }
+
+    pub fn months(&self) -> MonthDuration {
+        self.months
+    }
+
+    pub fn weeks(&self) -> WeekDuration {
+        self.weeks
+    }
+
+    pub fn days(&self) -> DayDuration {
+        self.days
+    }
+
+    pub fn hours(&self) -> HourDuration {
+        self.hours
+    }
+
+    pub fn minutes(&self) -> MinuteDuration {
+        self.minutes
+    }
+
+    pub fn seconds(&self) -> SecondDuration {
+        self.seconds
+    }
+
+    /// The fractional part of the lowest-order component
+    pub fn fraction(&self) -> Option<Fraction> {
+        self.fraction
+    }
+
+    pub fn with_years(self, years: YearDuration) -> Result<Self, Error> {
+        Self { years, ..self }.checked()
+    }
+
+    pub fn with_months(self, months: MonthDuration) -> Result<Self, Error> {
+        Self { months, ..self }.checked()
+    }
+
+    pub fn with_weeks(self, weeks: WeekDuration) -> Result<Self, Error> {
+        Self { weeks, ..self }.checked()
+    }
+
+    pub fn with_days(self, days: DayDuration) -> Result<Self, Error> {
+        Self { days, ..self }.checked()
+    }
+
+    pub fn with_hours(self, hours: HourDuration) -> Result<Self, Error> {
+        Self { hours, ..self }.checked()
+    }
+
+    pub fn with_minutes(self, minutes: MinuteDuration) -> Result<Self, Error> {
+        Self { minutes, ..self }.checked()
+    }
+
+    pub fn with_seconds(self, seconds: SecondDuration) -> Result<Self, Error> {
+        Self { seconds, ..self }.checked()
+    }
+
+    /// Set the fraction of the component in the unit of `fraction`
+    ///
+    /// A fraction of zero is the same as no fraction.
+    pub fn with_fraction(self, fraction: Fraction) -> Result<Self, Error> {
+        let fraction = Some(fraction).filter(|fraction| fraction.numerator != 0);
+        Self { fraction, ..self }.checked()
+    }
+
+    /// The components from years to seconds, with their units
+    fn components(&self) -> [(u64, Unit); 7] {
+        [
+            (self.years.0, Unit::Years),
+            (self.months.0, Unit::Months),
+            (self.weeks.0, Unit::Weeks),
+            (self.days.0, Unit::Days),
+            (self.hours.0, Unit::Hours),
+            (self.minutes.0, Unit::Minutes),
+            (self.seconds.0, Unit::Seconds),
+        ]
+    }
+
+    /// Fails with [`Error::Range`] when a component of a lower order than the
+    /// fraction is not zero, like the seconds in `PT1.5H2S`
+    fn checked(self) -> Result<Self, Error> {
+        match self.fraction {
+            Some(fraction)
+                if self
+                    .components()
+                    .iter()
+                    .any(|&(value, unit)| unit > fraction.unit && value != 0) =>
+            {
+                Err(Error::Range)
+            }
+            _ => Ok(self),
+        }
+    }
+
+    fn has_fraction_in(&self, units: &[Unit]) -> bool {
+        matches!(self.fraction, Some(fraction) if units.contains(&fraction.unit))
+    }
+
+    fn has_date(&self) -> bool {
+        self.years.0 != 0
+            || self.months.0 != 0
+            || self.weeks.0 != 0
+            || self.days.0 != 0
+            || self.has_fraction_in(&[Unit::Years, Unit::Months, Unit::Weeks, Unit::Days])
+    }
+
+    fn has_time(&self) -> bool {
+        self.hours.0 != 0
+            || self.minutes.0 != 0
+            || self.seconds.0 != 0
+            || self.has_fraction_in(&[Unit::Hours, Unit::Minutes, Unit::Seconds])
+    }
+
+    /// Years and months together as months, which only whole years and months can be
+    pub(crate) fn calendar_months(&self) -> Result<u64, Error> {
+        if self.has_fraction_in(&[Unit::Years, Unit::Months]) {
+            return Err(Error::CalendarDependent);
+        }
+        self.years
+            .0
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.months.0))
+            .ok_or(Error::Range)
+    }
+
+    /// Weeks, days and the time as nanoseconds, counting a day as 86400 seconds
+    ///
+    /// Fails when a fraction of a year or month can't be expressed exactly, or
+    /// the fraction is more precise than nanoseconds.
+    pub(crate) fn nominal_nanoseconds(&self) -> Result<i128, Error> {
+        let mut nanoseconds = 0;
+        for (value, unit) in &self.components()[2..] {
+            let length = unit.nanoseconds().expect("unit has a fixed length");
+            nanoseconds += *value as i128 * length;
+        }
+        match self.fraction {
+            Some(fraction) => Ok(nanoseconds + fraction.nanoseconds()?),
+            None => Ok(nanoseconds),
+        }
+    }
+
+    /// A duration of days and time, using the largest units possible
+    pub(crate) fn from_nanoseconds(negative: bool, nanoseconds: u128) -> Result<Self, Error> {
+        let days = nanoseconds / 86_400_000_000_000;
+        let seconds = (nanoseconds % 86_400_000_000_000 / 1_000_000_000) as u64;
+        let fraction = (nanoseconds % 1_000_000_000) as u64;
+        Ok(Duration {
+            negative,
+            days: DayDuration::new(days.try_into().map_err(|_| Error::Range)?),
+            hours: HourDuration::new(seconds / 3600),
+            minutes: MinuteDuration::new(seconds / 60 % 60),
+            seconds: SecondDuration::new(seconds % 60),
+            fraction: match fraction {
+                0 => None,
+                _ => Some(Fraction::new(Unit::Seconds, fraction, 9)?),
+            },
+            ..Duration::new()
+        })
+    }
+
+    /// The exact length of this duration when added to `anchor`
+    ///
+    /// Years and months are resolved against the date of `anchor` as in
+    /// [`PreciseShiftedDateTime::checked_add_duration`], so `P1M` from
+    /// 2024-01-31 is 29 days. The result is whole seconds, rounded down, and
+    /// the nanoseconds after them, like a Unix timestamp. Leap seconds are not
+    /// counted.
+    pub fn to_seconds_at<Y: YearDigits>(
+        &self,
+        anchor: PreciseShiftedDateTime<Y>,
+    ) -> Result<(i64, Nanosecond), Error> {
+        let end = anchor.checked_add_duration(self)?;
+        let nanoseconds = |date_time: PreciseShiftedDateTime<Y>| {
+            date_time.unix_timestamp() as i128 * 1_000_000_000
+                + u32::from(date_time.nanosecond) as i128
+        };
+        let length = nanoseconds(end) - nanoseconds(anchor);
+        let seconds = length
+            .div_euclid(1_000_000_000)
+            .try_into()
+            .map_err(|_| Error::Range)?;
+        Ok((
+            seconds,
+            Nanosecond::new(length.rem_euclid(1_000_000_000) as u64)?,
+        ))
+    }
+
+    /// The exact length of this duration when added to `anchor`, see [`Duration::to_seconds_at`]
+    ///
+    /// Fails with [`Error::Negative`] when the duration is negative.
+    pub fn to_std_at<Y: YearDigits>(
+        &self,
+        anchor: PreciseShiftedDateTime<Y>,
+    ) -> Result<std::time::Duration, Error> {
+        let (seconds, nanosecond) = self.to_seconds_at(anchor)?;
+        let seconds = u64::try_from(seconds).map_err(|_| Error::Negative)?;
+        Ok(std::time::Duration::new(seconds, nanosecond.into()))
+    }
+}
+
+/// The same duration in the other direction
+impl core::ops::Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+impl Default for Duration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        if !self.has_date() && !self.has_time() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (value, unit) in self.components() {
+            if unit == Unit::Hours {
+                if !self.has_time() {
+                    break;
+                }
+                write!(f, "T")?;
+            }
+            match self.fraction {
+                Some(fraction) if fraction.unit == unit => {
+                    write!(f, "{}.{}{}", value, fraction, unit.designator())?;
+                }
+                _ if value != 0 => write!(f, "{}{}", value, unit.designator())?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl str::FromStr for Duration {
+    type Err = Error;
+
+    /// Parse a duration like `P1Y2M3DT4H5M6.5S`
+    ///
+    /// Components can be left out, but the ones present must be in order and
+    /// at least one must be present. The duration can be negated with a
+    /// leading `-`, and the last component can have a fraction after a `.` or `,`.
+    /// That is the last component written, so `PT1.5H0S` is rejected even
+    /// though its seconds are zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let s = s.strip_prefix('P').ok_or(Error::Parse)?;
+        let (date, time) = match s.split_once('T') {
+            Some((_, "")) => return Err(Error::Parse),
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return Err(Error::Parse);
+        }
+
+        let mut duration = Duration::new();
+        duration.negative = negative;
+        let parts = [
+            (
+                date,
+                &[Unit::Years, Unit::Months, Unit::Weeks, Unit::Days][..],
+            ),
+            (
+                time.unwrap_or(""),
+                &[Unit::Hours, Unit::Minutes, Unit::Seconds][..],
+            ),
+        ];
+        for (mut rest, units) in parts {
+            for &unit in units {
+                let Some((value, after)) = split_component(rest, unit.designator()) else {
+                    continue;
+                };
+                if duration.fraction.is_some() {
+                    // Only the last component can have a fraction
+                    return Err(Error::Parse);
+                }
+                let (whole, fraction) = match value.split_once(['.', ',']) {
+                    Some((whole, fraction)) => (whole, Some(fraction)),
+                    None => (value, None),
+                };
+                let whole = whole.parse().map_err(Error::ParseInt)?;
+                match unit {
+                    Unit::Years => duration.years = YearDuration(whole),
+                    Unit::Months => duration.months = MonthDuration(whole),
+                    Unit::Weeks => duration.weeks = WeekDuration(whole),
+                    Unit::Days => duration.days = DayDuration(whole),
+                    Unit::Hours => duration.hours = HourDuration(whole),
+                    Unit::Minutes => duration.minutes = MinuteDuration(whole),
+                    Unit::Seconds => duration.seconds = SecondDuration(whole),
+                }
+                if let Some(fraction) = fraction {
+                    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                        return Err(Error::Parse);
+                    }
+                    let digits = fraction.len().try_into().map_err(|_| Error::Range)?;
+                    let numerator = fraction.parse().map_err(Error::ParseInt)?;
+                    duration.fraction = Some(Fraction::new(unit, numerator, digits)?);
+                }
+                rest = after;
+            }
+            if !rest.is_empty() {
+                return Err(Error::Parse);
+            }
+        }
+        if matches!(duration.fraction, Some(fraction) if fraction.numerator == 0) {
+            duration.fraction = None;
+        }
+        Ok(duration)
+    }
+}
+
+/// Converts durations without years or months, counting a day as 86400 seconds
+///
+/// Fails with [`Error::Negative`] for negative durations, with
+/// [`Error::CalendarDependent`] for durations with years or months, and with
+/// [`Error::SubNanosecond`] for fractions smaller than a nanosecond.
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        if value.calendar_months()? != 0 {
+            return Err(Error::CalendarDependent);
+        }
+        let nanoseconds = value.nominal_nanoseconds()?;
+        if value.negative && nanoseconds != 0 {
+            return Err(Error::Negative);
+        }
+        let seconds = (nanoseconds / 1_000_000_000)
+            .try_into()
+            .map_err(|_| Error::Range)?;
+        Ok(std::time::Duration::new(
+            seconds,
+            (nanoseconds % 1_000_000_000) as u32,
+        ))
+    }
+}
+
+/// Converts durations without years or months, counting a day as 86400 seconds
+///
+/// Fails with [`Error::CalendarDependent`] for durations with years or months,
+/// and with [`Error::SubNanosecond`] for fractions smaller than a nanosecond.
+#[cfg(feature = "time")]
+impl TryFrom<Duration> for time::Duration {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        if value.calendar_months()? != 0 {
+            return Err(Error::CalendarDependent);
+        }
+        let nanoseconds = value.nominal_nanoseconds()?;
+        let nanoseconds = if value.negative {
+            -nanoseconds
+        } else {
+            nanoseconds
+        };
+        let seconds = (nanoseconds / 1_000_000_000)
+            .try_into()
+            .map_err(|_| Error::Range)?;
+        Ok(time::Duration::new(
+            seconds,
+            (nanoseconds % 1_000_000_000) as i32,
+        ))
+    }
+}
+
+/// Converts to days and time, using the largest units possible
+#[cfg(feature = "time")]
+impl From<time::Duration> for Duration {
+    fn from(value: time::Duration) -> Self {
+        Duration::from_nanoseconds(value.is_negative(), value.unsigned_abs().as_nanos())
+            .expect("time::Duration has less than 2^64 days")
+    }
+}
+
+#[cfg(feature = "time")]
+macro_rules! impl_try_into_time {
+    ($structtype:ident, $seconds:expr) => {
+        impl TryFrom<$structtype> for time::Duration {
+            type Error = Error;
+
+            fn try_from(value: $structtype) -> Result<Self, Self::Error> {
+                value
+                    .0
+                    .checked_mul($seconds)
+                    .and_then(|seconds| i64::try_from(seconds).ok())
+                    .map(time::Duration::seconds)
+                    .ok_or(Error::Range)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "time")]
+impl_try_into_time!(WeekDuration, 60 * 60 * 24 * 7);
+#[cfg(feature = "time")]
+impl_try_into_time!(DayDuration, 60 * 60 * 24);
+#[cfg(feature = "time")]
+impl_try_into_time!(HourDuration, 60 * 60);
+#[cfg(feature = "time")]
+impl_try_into_time!(MinuteDuration, 60);
+#[cfg(feature = "time")]
+impl_try_into_time!(SecondDuration, 1);
+
+/// Converts each component, adding a fraction to the seconds and nanoseconds
+///
+/// Fails with [`Error::CalendarDependent`] for a fraction of a year or month,
+/// and with [`Error::Range`] for components larger than jiff supports.
+#[cfg(feature = "jiff")]
+impl TryFrom<Duration> for jiff::Span {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let fraction = match value.fraction {
+            Some(fraction) => fraction.nanoseconds()?,
+            None => 0,
+        };
+        let sign = if value.negative { -1 } else { 1 };
+        let signed = |component: u64| {
+            i64::try_from(component)
+                .map(|component| sign * component)
+                .map_err(|_| Error::Range)
+        };
+        let seconds = value
+            .seconds
+            .0
+            .checked_add((fraction / 1_000_000_000) as u64)
+            .ok_or(Error::Range)?;
+        let range = |_: jiff::Error| Error::Range;
+        jiff::Span::new()
+            .try_years(signed(value.years.0)?)
+            .map_err(range)?
+            .try_months(signed(value.months.0)?)
+            .map_err(range)?
+            .try_weeks(signed(value.weeks.0)?)
+            .map_err(range)?
+            .try_days(signed(value.days.0)?)
+            .map_err(range)?
+            .try_hours(signed(value.hours.0)?)
+            .map_err(range)?
+            .try_minutes(signed(value.minutes.0)?)
+            .map_err(range)?
+            .try_seconds(signed(seconds)?)
+            .map_err(range)?
+            .try_nanoseconds(sign * (fraction % 1_000_000_000) as i64)
+            .map_err(range)
+    }
+}
+
+/// Converts each component, with the milliseconds, microseconds and nanoseconds as seconds
+#[cfg(feature = "jiff")]
+impl From<jiff::Span> for Duration {
+    fn from(value: jiff::Span) -> Self {
+        let subsecond = value.get_milliseconds().unsigned_abs() as u128 * 1_000_000
+            + value.get_microseconds().unsigned_abs() as u128 * 1_000
+            + value.get_nanoseconds().unsigned_abs() as u128;
+        let fraction = (subsecond % 1_000_000_000) as u64;
+        Duration {
+            negative: value.signum() < 0,
+            years: YearDuration::new(value.get_years().unsigned_abs().into()),
+            months: MonthDuration::new(value.get_months().unsigned_abs().into()),
+            weeks: WeekDuration::new(value.get_weeks().unsigned_abs().into()),
+            days: DayDuration::new(value.get_days().unsigned_abs().into()),
+            hours: HourDuration::new(value.get_hours().unsigned_abs().into()),
+            minutes: MinuteDuration::new(value.get_minutes().unsigned_abs()),
+            seconds: SecondDuration::new(
+                value.get_seconds().unsigned_abs() + (subsecond / 1_000_000_000) as u64,
+            ),
+            fraction: match fraction {
+                0 => None,
+                _ => {
+                    Some(Fraction::new(Unit::Seconds, fraction, 9).expect("fraction has 9 digits"))
+                }
+            },
+        }
+    }
+}
+
+/// Split off a component ending in `designator`, if the input starts with one
+fn split_component(s: &str, designator: char) -> Option<(&str, &str)> {
+    let end = s.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))?;
+    if end == 0 || !s[end..].starts_with(designator) {
+        return None;
+    }
+    Some((&s[..end], &s[end + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DayDuration, Duration, Fraction, HourDuration, MinuteDuration, SecondDuration, Unit,
+        YearDuration,
+    };
+    use crate::components::Error;
+
+    #[test]
+    fn test_parse_and_format() {
+        for s in [
+            "P1Y2M3DT4H5M6S",
+            "P3W",
+            "PT0S",
+            "PT1.5S",
+            "P1Y",
+            "PT36H",
+            "P1DT0.000001S",
+            "-P1D",
+            "PT1.5H",
+            "P0.5Y",
+            "P1Y2.25M",
+            "PT0.0000000001S",
+        ] {
+            let duration: Duration = s.parse().unwrap();
+            assert_eq!(format!("{}", duration), s);
+        }
+        let duration: Duration = "PT1,25S".parse().unwrap();
+        assert_eq!(format!("{}", duration), "PT1.25S");
+        let duration: Duration = "P0Y0DT0H".parse().unwrap();
+        assert_eq!(format!("{}", duration), "PT0S");
+        let duration: Duration = "PT1.50H".parse().unwrap();
+        assert_eq!(duration.fraction().unwrap().unit(), Unit::Hours);
+        assert_eq!(duration.fraction().unwrap().numerator(), 5);
+    }
+
+    #[test]
+    fn test_constructors() {
+        let duration = Duration::new()
+            .with_years(YearDuration::new(1))
+            .and_then(|d| d.with_hours(HourDuration::new(2)))
+            .and_then(|d| d.with_fraction(Fraction::new(Unit::Hours, 25, 2)?))
+            .unwrap();
+        assert_eq!(duration, "P1YT2.25H".parse().unwrap());
+        assert_eq!(-duration, "-P1YT2.25H".parse().unwrap());
+        assert!(!duration.is_negative());
+        assert_eq!(u64::from(duration.hours()), 2);
+        assert_eq!(u64::from(duration.seconds()), 0);
+        // Nothing of a lower order than the fraction
+        assert!(matches!(
+            duration.with_seconds(SecondDuration::new(1)),
+            Err(Error::Range)
+        ));
+        assert!(duration.with_seconds(SecondDuration::new(0)).is_ok());
+        assert!(duration.with_days(DayDuration::new(3)).is_ok());
+        let minutes = Duration::new()
+            .with_minutes(MinuteDuration::new(1))
+            .unwrap();
+        assert!(matches!(
+            minutes.with_fraction(Fraction::new(Unit::Hours, 5, 1).unwrap()),
+            Err(Error::Range)
+        ));
+        let zero = minutes
+            .with_fraction(Fraction::new(Unit::Seconds, 0, 3).unwrap())
+            .unwrap();
+        assert_eq!(zero.fraction(), None);
+        assert_eq!(zero, "PT1M".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for s in [
+            "", "P", "PT", "1Y", "P1S", "PT1D", "P1M1Y", "P1YT", "PY", "P1.5Y2M", "PT1.5H2S",
+            "PT1.S", "P.5Y", "+P1D", "--P1D", "P-1D",
+        ] {
+            assert!(s.parse::<Duration>().is_err(), "{}", s);
+        }
+        // The fraction is on the last component written, even when the
+        // components after it are zero
+        for s in ["PT1.5H0S", "P1.5DT0H", "P0.5Y0M"] {
+            assert!(s.parse::<Duration>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_to_std() {
+        let to_std = |s: &str| std::time::Duration::try_from(s.parse::<Duration>().unwrap());
+        assert_eq!(
+            to_std("P1DT1.5H").unwrap(),
+            std::time::Duration::from_secs(86400 + 5400)
+        );
+        assert_eq!(
+            to_std("PT0.25S").unwrap(),
+            std::time::Duration::from_millis(250)
+        );
+        assert_eq!(to_std("-PT0S").unwrap(), std::time::Duration::ZERO);
+        assert!(matches!(to_std("-PT1S"), Err(Error::Negative)));
+        assert!(matches!(to_std("P1M"), Err(Error::CalendarDependent)));
+        assert!(matches!(to_std("P0.5Y"), Err(Error::CalendarDependent)));
+        assert!(matches!(
+            to_std("PT0.0000000001S"),
+            Err(Error::SubNanosecond)
+        ));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        let to_time = |s: &str| time::Duration::try_from(s.parse::<Duration>().unwrap());
+        assert_eq!(
+            to_time("-P1DT0.5S").unwrap(),
+            -time::Duration::new(86400, 500_000_000)
+        );
+        assert!(matches!(to_time("P1Y"), Err(Error::CalendarDependent)));
+        let duration = Duration::from(time::Duration::new(-(86400 + 3661), -250_000_000));
+        assert_eq!(format!("{}", duration), "-P1DT1H1M1.25S");
+        assert_eq!(
+            time::Duration::try_from(super::DayDuration::new(2)).unwrap(),
+            time::Duration::days(2)
+        );
+        assert!(time::Duration::try_from(super::SecondDuration::new(u64::MAX)).is_err());
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff() {
+        let to_span = |s: &str| jiff::Span::try_from(s.parse::<Duration>().unwrap());
+        let span = to_span("-P1Y2M3DT4H0.5M").unwrap();
+        assert_eq!(span.get_years(), -1);
+        assert_eq!(span.get_months(), -2);
+        assert_eq!(span.get_minutes(), 0);
+        assert_eq!(span.get_seconds(), -30);
+        assert_eq!(format!("{}", Duration::from(span)), "-P1Y2M3DT4H30S");
+        let span = to_span("PT1.000000001S").unwrap();
+        assert_eq!(format!("{}", Duration::from(span)), "PT1.000000001S");
+        assert!(matches!(to_span("P0.5Y"), Err(Error::CalendarDependent)));
+        assert!(matches!(to_span("P20000Y"), Err(Error::Range)));
+        let span = jiff::Span::new().milliseconds(1500).microseconds(2);
+        assert_eq!(format!("{}", Duration::from(span)), "PT1.500002S");
+    }
+
+    #[test]
+    fn test_to_std_at() {
+        let to_std_at = |s: &str, anchor: &str| {
+            let anchor = crate::parse_rfc3339_datetime(anchor).unwrap();
+            s.parse::<Duration>().unwrap().to_std_at(anchor)
+        };
+        let days = |days: u64| std::time::Duration::from_secs(days * 86400);
+        assert_eq!(to_std_at("P1M", "2024-01-31T00:00:00Z").unwrap(), days(29));
+        assert_eq!(to_std_at("P1M", "2023-01-31T00:00:00Z").unwrap(), days(28));
+        assert_eq!(to_std_at("P1Y", "2024-01-01T00:00:00Z").unwrap(), days(366));
+        assert_eq!(
+            to_std_at("P1MT0.5S", "2024-02-01T12:00:00Z").unwrap(),
+            days(29) + std::time::Duration::from_millis(500)
+        );
+        assert!(matches!(
+            to_std_at("-P1M", "2024-01-31T00:00:00Z"),
+            Err(Error::Negative)
+        ));
+
+        let anchor = crate::parse_rfc3339_datetime("2024-03-31T00:00:00.25Z").unwrap();
+        let (seconds, nanosecond) = "-P1M"
+            .parse::<Duration>()
+            .unwrap()
+            .to_seconds_at(anchor)
+            .unwrap();
+        assert_eq!(seconds, -31 * 86400);
+        assert_eq!(u32::from(nanosecond), 0);
+        let (seconds, nanosecond) = "-PT0.25S"
+            .parse::<Duration>()
+            .unwrap()
+            .to_seconds_at(anchor)
+            .unwrap();
+        assert_eq!(seconds, -1);
+        assert_eq!(u32::from(nanosecond), 750_000_000);
+    }
+}
@@ -0,0 +1,194 @@
+//! Fast path for the most common RFC 3339 layout
+//!
+//! `YYYY-MM-DDThh:mm:ss[.f]Z` always has the digits and separators at the
+//! same positions, so the first 19 bytes are checked and decoded eight at a
+//! time: separators are compared under a mask, the digits are checked for
+//! being `0` to `9` in every lane at once, and pairs of digits are combined
+//! with a single multiplication. Anything else returns `None` so the general
+//! parser can handle it, and report the error.
+
+use crate::{
+    combined::{check_time, PreciseShiftedDateTime},
+    components::{Day, Hour, Minute, Month, Nanosecond, Second, Timeshift, Year},
+};
+
+/// Bytes 0 to 8, `YYYY-MM-`
+const DIGITS_A: u64 = lanes(&[0, 1, 2, 3, 5, 6]);
+const SEPARATORS_A: u64 = lanes(&[4, 7]);
+const EXPECTED_A: u64 = (b'-' as u64) << 32 | (b'-' as u64) << 56;
+/// Bytes 8 to 16, `DDThh:mm`
+const DIGITS_B: u64 = lanes(&[0, 1, 3, 4, 6, 7]);
+const SEPARATORS_B: u64 = lanes(&[2, 5]);
+const EXPECTED_B: u64 = (b'T' as u64) << 16 | (b':' as u64) << 40;
+/// Bytes 15 to 19, `m:ss`, of which the minute was already checked
+const DIGITS_C: u64 = lanes(&[2, 3]);
+const SEPARATORS_C: u64 = lanes(&[1]);
+const EXPECTED_C: u64 = (b':' as u64) << 8;
+
+/// A mask with all bits of the given byte lanes set
+const fn lanes(lanes: &[u32]) -> u64 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < lanes.len() {
+        mask |= 0xff << (lanes[i] * 8);
+        i += 1;
+    }
+    mask
+}
+
+/// Check a little-endian word, returning the digits as values from 0 to 9
+/// with the other lanes cleared, and whether any byte was wrong
+fn digits(word: u64, digits: u64, separators: u64, expected: u64) -> (u64, u64) {
+    let values = word.wrapping_sub(0x3030_3030_3030_3030 & digits);
+    // A lane above 9 gets its high bit set by adding 0x76, and one that was
+    // below `0` or not ASCII already has it set. A carry or borrow into the
+    // next lane can only happen when this lane is already wrong.
+    let not_digit = (values.wrapping_add(0x7676_7676_7676_7676 & digits) | values)
+        & 0x8080_8080_8080_8080
+        & digits;
+    let wrong_separator = (word & separators) ^ expected;
+    (values & digits, not_digit | wrong_separator)
+}
+
+/// Combine every digit with the next one, so lane `i` holds `10 * d[i] + d[i + 1]`
+fn pairs(values: u64) -> u64 {
+    values.wrapping_mul(10).wrapping_add(values >> 8)
+}
+
+fn lane(word: u64, lane: u32) -> u64 {
+    (word >> (lane * 8)) & 0xff
+}
+
+fn load(bytes: &[u8]) -> u64 {
+    let mut word = [0; 8];
+    word[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(word)
+}
+
+/// Parse `YYYY-MM-DDThh:mm:ss[.f]Z`, or `None` for anything else
+pub(crate) fn parse_rfc3339_utc(data: &[u8]) -> Option<PreciseShiftedDateTime> {
+    let (head, tail) = data.split_at_checked(19)?;
+    let (a, bad_a) = digits(load(&head[0..8]), DIGITS_A, SEPARATORS_A, EXPECTED_A);
+    let (b, bad_b) = digits(load(&head[8..16]), DIGITS_B, SEPARATORS_B, EXPECTED_B);
+    let (c, bad_c) = digits(load(&head[15..19]), DIGITS_C, SEPARATORS_C, EXPECTED_C);
+    if bad_a | bad_b | bad_c != 0 {
+        return None;
+    }
+    let (a, b, c) = (pairs(a), pairs(b), pairs(c));
+    let year = lane(a, 0) * 100 + lane(a, 2);
+
+    let tail = match tail {
+        [b'.', rest @ ..] => rest,
+        _ => tail,
+    };
+    let fraction_digits = tail.iter().take_while(|c| c.is_ascii_digit()).count();
+    if tail.len() != fraction_digits + 1 || tail[fraction_digits] != b'Z' {
+        return None;
+    }
+    // A `.` has to be followed by digits, and there can be at most nine
+    let has_dot = data[19] == b'.';
+    if has_dot != (fraction_digits > 0) || fraction_digits > 9 {
+        return None;
+    }
+    let nanosecond = tail[..fraction_digits]
+        .iter()
+        .chain(core::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |value, &c| value * 10 + u64::from(c - b'0'));
+
+    let hour = Hour::new(lane(b, 3)).ok()?;
+    let minute = Minute::new(lane(b, 6)).ok()?;
+    let second = Second::new(lane(c, 2)).ok()?;
+    let nanosecond = Nanosecond::new(nanosecond).ok()?;
+    check_time(hour, minute, second, nanosecond, Some(Timeshift::Utc)).ok()?;
+    Some(PreciseShiftedDateTime {
+        year: Year::new(year as i32).ok()?,
+        month: Month::new(lane(a, 5)).ok()?,
+        day: Day::new(lane(b, 0)).ok()?,
+        hour,
+        minute,
+        second,
+        nanosecond,
+        timeshift: Timeshift::Utc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_rfc3339_utc;
+    use crate::{
+        parse::{ParseContext, Parser},
+        PreciseShiftedDateTime,
+    };
+
+    fn general(s: &str) -> Option<PreciseShiftedDateTime> {
+        let mut parser: Parser = ParseContext::new_rfc3339().into_parser();
+        match parser.parse_precise_shifted_date_time(s.as_bytes()) {
+            Ok([]) => parser.build_precise_shifted_date_time().ok(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_fast_path() {
+        for s in [
+            "2023-09-17T09:08:58Z",
+            "2023-09-17T09:08:58.7Z",
+            "2023-09-17T09:08:58.763Z",
+            "2023-09-17T09:08:58.763072123Z",
+            "0000-01-01T00:00:00Z",
+            "9999-12-31T23:59:59.999999999Z",
+            "2016-12-31T23:59:60Z",
+            "2023-09-17T24:00:00Z",
+        ] {
+            let fast = parse_rfc3339_utc(s.as_bytes());
+            assert!(fast.is_some(), "{}", s);
+            assert_eq!(fast, general(s), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_fall_back() {
+        for s in [
+            "",
+            "2023-09-17T09:08:58",
+            "2023-09-17T09:08:58z",
+            "2023-09-17t09:08:58Z",
+            "2023-09-17 09:08:58Z",
+            "2023-09-17T09:08:58+02:00",
+            "2023-09-17T09:08:58.Z",
+            "2023-09-17T09:08:58.1234567891Z",
+            "2023-09-17T09:08:58Z ",
+            "2023-09-17T09:08:5/Z",
+            "2023-09-17T09:08:5:Z",
+            "2023-09-1/T09:08:58Z",
+            "2023/09-17T09:08:58Z",
+            "2023-09-17T09-08:58Z",
+            "2023-09-17T09:08-58Z",
+            "2023-13-17T09:08:58Z",
+            "2023-09-00T09:08:58Z",
+            "2023-09-17T25:08:58Z",
+            "2023-09-17T24:00:01Z",
+            "2023-09-17T09:08:60Z",
+            "2023-09-17T09:08:61Z",
+            "+023-09-17T09:08:58Z",
+            "2\u{e9}3-09-17T09:08:58Z",
+        ] {
+            assert_eq!(parse_rfc3339_utc(s.as_bytes()), None, "{}", s);
+        }
+        // Every single byte change of a valid input is either decoded the
+        // same as the general parser or left to it
+        let valid = b"2023-09-17T09:08:58.763Z";
+        for position in 0..valid.len() {
+            for byte in 0..=255u8 {
+                let mut input = *valid;
+                input[position] = byte;
+                let Some(fast) = parse_rfc3339_utc(&input) else {
+                    continue;
+                };
+                let s = core::str::from_utf8(&input).unwrap();
+                assert_eq!(Some(fast), general(s), "{}", s);
+            }
+        }
+    }
+}
@@ -0,0 +1,790 @@
+//! Parsing and formatting with a pattern
+//!
+//! A [`Format`] is compiled once from a pattern like `%d/%m/%Y %H:%M:%S.%3f`
+//! and can then parse and format any number of values. The directives are a
+//! subset of `strftime`:
+//!
+//! | Directive | Meaning |
+//! |-----------|---------|
+//! | `%Y` | Four digit year |
+//! | `%m`, `%d` | Two digit month and day |
+//! | `%b` | Month name like `Sep`, parsed ignoring case |
+//! | `%a` | Day of the week like `Sun`, checked against the date when parsing |
+//! | `%H`, `%M`, `%S` | Two digit hour, minute and second |
+//! | `%f` | Fraction of a second, one to nine digits when parsing and nine when formatting |
+//! | `%3f`, `%6f`, `%9f` | Fraction of a second with exactly that many digits |
+//! | `%z`, `%:z` | Offset like `+0200` or `+02:00`, also accepting `Z` when parsing |
+//! | `%F`, `%T` | `%Y-%m-%d` and `%H:%M:%S` |
+//! | `%%` | A literal `%` |
+//!
+//! Every other character has to match exactly. Each field can only appear
+//! once in a pattern.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::format::Format;
+//! # use datetimeparse::PreciseLocalDateTime;
+//! let format: Format = "%d/%m/%Y %H:%M:%S.%3f".parse().unwrap();
+//! let dt: PreciseLocalDateTime = format.parse("17/09/2023 09:08:58.763").unwrap();
+//! assert_eq!(dt.to_string(), "2023-09-17T09:08:58.763");
+//! assert_eq!(format.format(dt).unwrap(), "17/09/2023 09:08:58.763");
+//! ```
+
+use core::{
+    fmt::{self, Write},
+    ops::{Deref, DerefMut},
+    str,
+};
+
+use crate::{
+    combined::{
+        LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime, PreciseLocalTime,
+        PreciseShiftedDateTime, ShiftedDateTime,
+    },
+    components::{self, Day, Hour, Minute, Month, Nanosecond, Second, Timeshift, Weekday, Year},
+    parse::{BuildError, Element, Parser},
+    parse_utils::{self, SyntaxError},
+    rfc5322::{day_name, find_name, month_name, DAY_NAMES, MONTH_NAMES},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The pattern has an unknown or repeated directive at this byte
+    Pattern {
+        position: usize,
+    },
+    /// The input does not match the pattern, starting at this byte
+    Syntax {
+        position: usize,
+    },
+    /// The pattern does not have a field the value needs
+    MissingField(Field),
+    /// The day of the week does not match the date
+    Weekday,
+    DateTime(components::Error),
+    /// The fields could not be combined into the value
+    Build(BuildError<components::SimpleYear>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Pattern { position } => write!(f, "invalid pattern at byte {}", position),
+            Error::Syntax { position } => write!(f, "input does not match at byte {}", position),
+            Error::MissingField(field) => write!(f, "missing field {:?}", field),
+            Error::Weekday => write!(f, "day of the week does not match the date"),
+            Error::DateTime(e) => write!(f, "invalid date or time: {}", e),
+            Error::Build(e) => write!(f, "cannot build the value: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DateTime(e) => Some(e),
+            Error::Build(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::DateTime(value)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(value: SyntaxError) -> Self {
+        Error::Syntax {
+            position: value.position,
+        }
+    }
+}
+
+impl From<BuildError<components::SimpleYear>> for Error {
+    fn from(value: BuildError<components::SimpleYear>) -> Self {
+        match value {
+            BuildError::Invalid(e) => Error::DateTime(e),
+            e => Error::Build(e),
+        }
+    }
+}
+
+/// A part of a date-time that a pattern reads or writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Nanosecond,
+    Timeshift,
+}
+
+/// The fields of a value, as read by [`Format::parse`] or written by [`Format::format`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fields {
+    pub year: Option<Year>,
+    pub month: Option<Month>,
+    pub day: Option<Day>,
+    pub hour: Option<Hour>,
+    pub minute: Option<Minute>,
+    pub second: Option<Second>,
+    pub nanosecond: Option<Nanosecond>,
+    pub timeshift: Option<Timeshift>,
+}
+
+impl Fields {
+    fn date(&self) -> Option<LocalDate> {
+        Some(LocalDate::new(self.year?, self.month?, self.day?))
+    }
+
+    /// Push `elements` in order, with a missing fraction as zero
+    fn push_elements(&self, parser: &mut Parser, elements: &[Field]) -> Result<(), Error> {
+        let missing = Error::MissingField;
+        for &field in elements {
+            parser.push_element(match field {
+                Field::Year => Element::Year(self.year.ok_or(missing(field))?),
+                Field::Month => Element::Month(self.month.ok_or(missing(field))?),
+                Field::Day => Element::Day(self.day.ok_or(missing(field))?),
+                Field::Hour => Element::Hour(self.hour.ok_or(missing(field))?),
+                Field::Minute => Element::Minute(self.minute.ok_or(missing(field))?),
+                Field::Second => Element::Second(self.second.ok_or(missing(field))?),
+                Field::Nanosecond => match self.nanosecond {
+                    Some(nanosecond) => Element::Nanosecond(nanosecond),
+                    None => Element::Nanosecond(Nanosecond::new(0)?),
+                },
+                Field::Timeshift => Element::Timeshift(self.timeshift.ok_or(missing(field))?),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A type that can be built from the fields parsed with a [`Format`]
+pub trait FromFields: Sized {
+    /// The fields a pattern needs to parse this type
+    const REQUIRED: &'static [Field];
+
+    fn from_fields(fields: Fields) -> Result<Self, Error>;
+}
+
+macro_rules! impl_fields {
+    ($type:ty, $build:ident, [$($required:ident),*], [$($element:ident),*]) => {
+        /// Fields that are not part of the type are ignored
+        impl FromFields for $type {
+            const REQUIRED: &'static [Field] = &[$(Field::$required),*];
+
+            fn from_fields(fields: Fields) -> Result<Self, Error> {
+                let mut parser = Parser::new();
+                fields.push_elements(&mut parser, &[$(Field::$element),*])?;
+                Ok(parser.$build()?)
+            }
+        }
+    };
+}
+
+impl_fields!(
+    LocalDate,
+    build_date,
+    [Year, Month, Day],
+    [Year, Month, Day]
+);
+impl_fields!(
+    LocalTime,
+    build_time,
+    [Hour, Minute, Second],
+    [Hour, Minute, Second]
+);
+impl_fields!(
+    PreciseLocalTime,
+    build_precise_local_time,
+    [Hour, Minute, Second],
+    [Hour, Minute, Second, Nanosecond]
+);
+impl_fields!(
+    LocalDateTime,
+    build_local_date_time,
+    [Year, Month, Day, Hour, Minute, Second],
+    [Year, Month, Day, Hour, Minute, Second]
+);
+impl_fields!(
+    PreciseLocalDateTime,
+    build_precise_local_date_time,
+    [Year, Month, Day, Hour, Minute, Second],
+    [Year, Month, Day, Hour, Minute, Second, Nanosecond]
+);
+impl_fields!(
+    ShiftedDateTime,
+    build_shifted_date_time,
+    [Year, Month, Day, Hour, Minute, Second, Timeshift],
+    [Year, Month, Day, Hour, Minute, Second, Timeshift]
+);
+impl_fields!(
+    PreciseShiftedDateTime,
+    build_precise_shifted_date_time,
+    [Year, Month, Day, Hour, Minute, Second, Timeshift],
+    [Year, Month, Day, Hour, Minute, Second, Nanosecond, Timeshift]
+);
+
+impl From<LocalDate> for Fields {
+    fn from(value: LocalDate) -> Self {
+        Fields {
+            year: Some(value.year),
+            month: Some(value.month),
+            day: Some(value.day),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LocalTime> for Fields {
+    fn from(value: LocalTime) -> Self {
+        Fields {
+            hour: Some(value.hour),
+            minute: Some(value.minute),
+            second: Some(value.second),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PreciseLocalTime> for Fields {
+    fn from(value: PreciseLocalTime) -> Self {
+        Fields {
+            hour: Some(value.hour),
+            minute: Some(value.minute),
+            second: Some(value.second),
+            nanosecond: Some(value.nanosecond),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LocalDateTime> for Fields {
+    fn from(value: LocalDateTime) -> Self {
+        Fields {
+            year: Some(value.year),
+            month: Some(value.month),
+            day: Some(value.day),
+            hour: Some(value.hour),
+            minute: Some(value.minute),
+            second: Some(value.second),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PreciseLocalDateTime> for Fields {
+    fn from(value: PreciseLocalDateTime) -> Self {
+        Fields {
+            nanosecond: Some(value.nanosecond),
+            ..LocalDateTime::new(
+                value.year,
+                value.month,
+                value.day,
+                value.hour,
+                value.minute,
+                value.second,
+            )
+            .into()
+        }
+    }
+}
+
+impl From<ShiftedDateTime> for Fields {
+    fn from(value: ShiftedDateTime) -> Self {
+        Fields {
+            timeshift: Some(value.timeshift),
+            ..LocalDateTime::new(
+                value.year,
+                value.month,
+                value.day,
+                value.hour,
+                value.minute,
+                value.second,
+            )
+            .into()
+        }
+    }
+}
+
+impl From<PreciseShiftedDateTime> for Fields {
+    fn from(value: PreciseShiftedDateTime) -> Self {
+        Fields {
+            nanosecond: Some(value.nanosecond),
+            timeshift: Some(value.timeshift),
+            ..LocalDateTime::new(
+                value.year,
+                value.month,
+                value.day,
+                value.hour,
+                value.minute,
+                value.second,
+            )
+            .into()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Year,
+    Month,
+    MonthName,
+    Day,
+    Weekday,
+    Hour,
+    Minute,
+    Second,
+    /// With a fixed number of digits, or one to nine
+    Fraction(Option<usize>),
+    Offset {
+        colon: bool,
+    },
+}
+
+impl Item {
+    fn field(&self) -> Option<Field> {
+        match self {
+            Item::Literal(_) | Item::Weekday => None,
+            Item::Year => Some(Field::Year),
+            Item::Month | Item::MonthName => Some(Field::Month),
+            Item::Day => Some(Field::Day),
+            Item::Hour => Some(Field::Hour),
+            Item::Minute => Some(Field::Minute),
+            Item::Second => Some(Field::Second),
+            Item::Fraction(_) => Some(Field::Nanosecond),
+            Item::Offset { .. } => Some(Field::Timeshift),
+        }
+    }
+}
+
+/// A compiled pattern, see the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    pattern: String,
+    items: Vec<Item>,
+}
+
+impl Format {
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        let mut items: Vec<Item> = Vec::new();
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((position, c)) = chars.next() {
+            if c != '%' {
+                match items.last_mut() {
+                    Some(Item::Literal(literal)) => literal.push(c),
+                    _ => items.push(Item::Literal(c.to_string())),
+                }
+                continue;
+            }
+            let error = Error::Pattern { position };
+            let mut directive = String::new();
+            while let Some((_, c)) = chars.next_if(|(_, c)| matches!(c, ':' | '0'..='9')) {
+                directive.push(c);
+            }
+            directive.push(chars.next().ok_or(error)?.1);
+            let new_items = match directive.as_str() {
+                "Y" => vec![Item::Year],
+                "m" => vec![Item::Month],
+                "b" => vec![Item::MonthName],
+                "d" => vec![Item::Day],
+                "a" => vec![Item::Weekday],
+                "H" => vec![Item::Hour],
+                "M" => vec![Item::Minute],
+                "S" => vec![Item::Second],
+                "f" => vec![Item::Fraction(None)],
+                "3f" => vec![Item::Fraction(Some(3))],
+                "6f" => vec![Item::Fraction(Some(6))],
+                "9f" => vec![Item::Fraction(Some(9))],
+                "z" => vec![Item::Offset { colon: false }],
+                ":z" => vec![Item::Offset { colon: true }],
+                "F" => vec![
+                    Item::Year,
+                    Item::Literal("-".into()),
+                    Item::Month,
+                    Item::Literal("-".into()),
+                    Item::Day,
+                ],
+                "T" => vec![
+                    Item::Hour,
+                    Item::Literal(":".into()),
+                    Item::Minute,
+                    Item::Literal(":".into()),
+                    Item::Second,
+                ],
+                "%" => vec![Item::Literal("%".into())],
+                _ => return Err(Error::Pattern { position }),
+            };
+            for item in new_items {
+                let repeated = item.field().is_some()
+                    && items.iter().any(|i| i.field() == item.field())
+                    || item == Item::Weekday && items.contains(&Item::Weekday);
+                if repeated {
+                    return Err(Error::Pattern { position });
+                }
+                match (items.last_mut(), item) {
+                    (Some(Item::Literal(literal)), Item::Literal(new)) => literal.push_str(&new),
+                    (_, item) => items.push(item),
+                }
+            }
+        }
+        Ok(Self {
+            pattern: pattern.to_string(),
+            items,
+        })
+    }
+
+    /// Check that the pattern has all fields needed to parse `T`
+    pub fn check<T: FromFields>(&self) -> Result<(), Error> {
+        match T::REQUIRED
+            .iter()
+            .find(|&&field| !self.items.iter().any(|item| item.field() == Some(field)))
+        {
+            Some(&field) => Err(Error::MissingField(field)),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse `s` into a value of type `T`
+    ///
+    /// Fails with [`Error::MissingField`] before looking at `s` when the
+    /// pattern can't produce a `T`.
+    pub fn parse<T: FromFields>(&self, s: &str) -> Result<T, Error> {
+        self.check::<T>()?;
+        T::from_fields(self.parse_fields(s)?)
+    }
+
+    /// Parse `s` into the fields that the pattern has
+    pub fn parse_fields(&self, s: &str) -> Result<Fields, Error> {
+        let mut scanner = Scanner(parse_utils::Scanner::new(s.as_bytes()));
+        let mut fields = Fields::default();
+        let mut weekday = None;
+        for item in &self.items {
+            match item {
+                Item::Literal(literal) => scanner.expect(literal.as_bytes())?,
+                Item::Year => fields.year = Some(Year::new(scanner.number(4)? as i32)?),
+                Item::Month => fields.month = Some(Month::new(scanner.number(2)?)?),
+                Item::MonthName => {
+                    let index = scanner.name(&MONTH_NAMES)?;
+                    fields.month = Some(Month::new(index as u64 + 1)?);
+                }
+                Item::Day => fields.day = Some(Day::new(scanner.number(2)?)?),
+                Item::Weekday => {
+                    let index = scanner.name(&DAY_NAMES)?;
+                    weekday = Some(Weekday::new(index as u64 + 1)?);
+                }
+                Item::Hour => fields.hour = Some(Hour::new(scanner.number(2)?)?),
+                Item::Minute => fields.minute = Some(scanner.minute()?),
+                Item::Second => fields.second = Some(Second::new(scanner.number(2)?)?),
+                Item::Fraction(digits) => fields.nanosecond = Some(scanner.fraction(*digits)?),
+                Item::Offset { colon } => fields.timeshift = Some(scanner.offset(*colon)?),
+            }
+        }
+        scanner.end()?;
+        if let (Some(month), Some(day)) = (fields.month, fields.day) {
+            // Without a year February has 29 days
+            let days_in_month = match fields.year {
+                Some(year) => year.days_in_month(month),
+                None => Year::new(2000)?.days_in_month(month),
+            };
+            if u8::from(day) > days_in_month {
+                return Err(components::Error::Range.into());
+            }
+        }
+        if let Some(weekday) = weekday {
+            let date = fields.date().ok_or(Error::MissingField(Field::Day))?;
+            if date.weekday() != weekday {
+                return Err(Error::Weekday);
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Format a value, failing when the pattern has a field the value does not
+    pub fn format(&self, value: impl Into<Fields>) -> Result<String, Error> {
+        let fields = value.into();
+        let missing = Error::MissingField;
+        let mut out = String::new();
+        for item in &self.items {
+            // Writing to a String can't fail
+            let _ = match item {
+                Item::Literal(literal) => write!(out, "{}", literal),
+                Item::Year => write!(out, "{}", fields.year.ok_or(missing(Field::Year))?),
+                Item::Month => write!(out, "{}", fields.month.ok_or(missing(Field::Month))?),
+                Item::MonthName => {
+                    let month = fields.month.ok_or(missing(Field::Month))?;
+                    write!(out, "{}", month_name(month))
+                }
+                Item::Day => write!(out, "{}", fields.day.ok_or(missing(Field::Day))?),
+                Item::Weekday => {
+                    let date = fields.date().ok_or(missing(Field::Day))?;
+                    write!(out, "{}", day_name(date.weekday()))
+                }
+                Item::Hour => write!(out, "{}", fields.hour.ok_or(missing(Field::Hour))?),
+                Item::Minute => write!(out, "{}", fields.minute.ok_or(missing(Field::Minute))?),
+                Item::Second => write!(out, "{}", fields.second.ok_or(missing(Field::Second))?),
+                Item::Fraction(digits) => {
+                    let nanosecond = fields.nanosecond.ok_or(missing(Field::Nanosecond))?;
+                    let nanosecond = format!("{:09}", u32::from(nanosecond));
+                    write!(out, "{}", &nanosecond[..digits.unwrap_or(9)])
+                }
+                Item::Offset { colon } => {
+                    let timeshift = fields.timeshift.ok_or(missing(Field::Timeshift))?;
+                    let (non_negative, hours, minutes) = match timeshift {
+                        Timeshift::Utc => (true, Hour::new(0)?, Minute::new(0)?),
+                        Timeshift::Offset {
+                            non_negative,
+                            hours,
+                            minutes,
+                        } => (non_negative, hours, minutes),
+                    };
+                    let sign = if non_negative { '+' } else { '-' };
+                    let colon = if *colon { ":" } else { "" };
+                    write!(out, "{}{}{}{}", sign, hours, colon, minutes)
+                }
+            };
+        }
+        Ok(out)
+    }
+}
+
+impl str::FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Writes the pattern the format was compiled from
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+/// Reads the input from left to right
+struct Scanner<'a>(parse_utils::Scanner<'a>);
+
+impl<'a> Deref for Scanner<'a> {
+    type Target = parse_utils::Scanner<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Scanner<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Scanner<'_> {
+    fn minute(&mut self) -> Result<Minute, Error> {
+        match self.number(2)? {
+            minute @ 0..=59 => Ok(Minute::new(minute)?),
+            _ => Err(components::Error::Range.into()),
+        }
+    }
+
+    fn name(&mut self, names: &[&str]) -> Result<usize, Error> {
+        match self.rest().get(..3).and_then(|name| find_name(names, name)) {
+            Some(index) => {
+                self.advance(3);
+                Ok(index)
+            }
+            None => Err(self.error()),
+        }
+    }
+
+    fn fraction(&mut self, digits: Option<usize>) -> Result<Nanosecond, Error> {
+        let digits = match digits {
+            Some(n) => self.digits(n)?,
+            None => {
+                let n = self
+                    .rest()
+                    .iter()
+                    .take(9)
+                    .take_while(|c| c.is_ascii_digit())
+                    .count();
+                if n == 0 {
+                    return Err(self.error());
+                }
+                self.digits(n)?
+            }
+        };
+        Ok(Nanosecond::new(parse_utils::nanoseconds(digits))?)
+    }
+
+    fn offset(&mut self, colon: bool) -> Result<Timeshift, Error> {
+        let non_negative = match self.peek() {
+            Some(b'Z') => {
+                self.advance(1);
+                return Ok(Timeshift::Utc);
+            }
+            Some(b'+') => true,
+            Some(b'-') => false,
+            _ => return Err(self.error()),
+        };
+        self.advance(1);
+        let hours = match self.number(2)? {
+            hours @ 0..=23 => Hour::new(hours)?,
+            _ => return Err(components::Error::Range.into()),
+        };
+        if colon {
+            self.expect(b":")?;
+        }
+        Ok(Timeshift::offset(non_negative, hours, self.minute()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildError, Error, Field, Fields, Format};
+    use crate::{
+        components, Day, LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime,
+        PreciseLocalTime, PreciseShiftedDateTime, ShiftedDateTime,
+    };
+
+    #[test]
+    fn test_parse() {
+        let format = Format::new("%d/%m/%Y %H:%M:%S.%3f").unwrap();
+        let dt: PreciseLocalDateTime = format.parse("17/09/2023 09:08:58.763").unwrap();
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58.763");
+        let date: LocalDate = format.parse("17/09/2023 09:08:58.763").unwrap();
+        assert_eq!(date.to_string(), "2023-09-17");
+
+        let format = Format::new("%a, %d %b %Y %T %z").unwrap();
+        let dt: ShiftedDateTime = format.parse("Sun, 17 sep 2023 09:08:58 -0400").unwrap();
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58-04:00");
+
+        let format = Format::new("%FT%T.%f%:z").unwrap();
+        let dt: PreciseShiftedDateTime = format.parse("2023-09-17T09:08:58.5Z").unwrap();
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58.5Z");
+
+        let format = Format::new("%H%M%S 100%%").unwrap();
+        let time: PreciseLocalTime = format.parse("090858 100%").unwrap();
+        assert_eq!(time.to_string(), "09:08:58.0");
+
+        let fields = Format::new("%d.%m.")
+            .unwrap()
+            .parse_fields("29.02.")
+            .unwrap();
+        assert_eq!(fields.day, Some(Day::new(29).unwrap()));
+        assert_eq!(fields.year, None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let format = Format::new("%d/%m/%Y %H:%M:%S").unwrap();
+        for (s, position) in [
+            ("17/09/2023 09:08", 16),
+            ("17/09/2023 09:08:58 ", 19),
+            ("17-09-2023 09:08:58", 2),
+            ("17/9/2023 09:08:58", 3),
+        ] {
+            assert!(
+                matches!(format.parse::<LocalTime>(s), Err(Error::Syntax { position: p }) if p == position),
+                "{}",
+                s
+            );
+        }
+        for s in [
+            "30/02/2023 09:08:58",
+            "17/09/2023 09:60:58",
+            "17/13/2023 09:08:58",
+        ] {
+            assert!(
+                matches!(format.parse_fields(s), Err(Error::DateTime(_))),
+                "{}",
+                s
+            );
+        }
+        let offset = Format::new("%T %z").unwrap();
+        assert!(offset.parse_fields("09:08:58 -2359").is_ok());
+        for s in ["09:08:58 +2400", "09:08:58 +0260"] {
+            assert!(
+                matches!(offset.parse_fields(s), Err(Error::DateTime(_))),
+                "{}",
+                s
+            );
+        }
+        assert!(matches!(
+            format.parse::<LocalDateTime>("17/09/2023 24:00:01"),
+            Err(Error::DateTime(components::Error::EndOfDay))
+        ));
+        assert!(matches!(
+            Format::new("%a %F").unwrap().parse_fields("Mon 2023-09-17"),
+            Err(Error::Weekday)
+        ));
+        assert!(matches!(
+            format.parse::<ShiftedDateTime>("not even looked at"),
+            Err(Error::MissingField(Field::Timeshift))
+        ));
+        assert!(matches!(
+            Format::new("%d/%m").unwrap().check::<LocalDate>(),
+            Err(Error::MissingField(Field::Year))
+        ));
+        assert!(format.check::<PreciseLocalDateTime>().is_ok());
+
+        let error = Error::from(BuildError::NotEnoughElements);
+        assert!(matches!(error, Error::Build(BuildError::NotEnoughElements)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_pattern() {
+        for (pattern, position) in [
+            ("%Y-%q", 3),
+            ("%Y %Y", 3),
+            ("%F %d", 3),
+            ("%b %m", 3),
+            ("%", 0),
+            ("%4f", 0),
+        ] {
+            assert!(
+                matches!(Format::new(pattern), Err(Error::Pattern { position: p }) if p == position),
+                "{}",
+                pattern
+            );
+        }
+        let format: Format = "%d/%m/%Y".parse().unwrap();
+        assert_eq!(format.to_string(), "%d/%m/%Y");
+    }
+
+    #[test]
+    fn test_format() {
+        let dt: PreciseShiftedDateTime = "2023-09-17T09:08:58.763072+02:00".parse().unwrap();
+        let format = |pattern: &str| Format::new(pattern).unwrap().format(dt).unwrap();
+        assert_eq!(format("%d/%m/%Y %H:%M:%S.%3f"), "17/09/2023 09:08:58.763");
+        assert_eq!(
+            format("%a, %d %b %Y %T %z"),
+            "Sun, 17 Sep 2023 09:08:58 +0200"
+        );
+        assert_eq!(format("%FT%T.%f%:z"), "2023-09-17T09:08:58.763072000+02:00");
+        assert_eq!(format("%6f %%"), "763072 %");
+
+        let date: LocalDate = "2023-09-17".parse().unwrap();
+        let format = Format::new("%F %H").unwrap();
+        assert!(matches!(
+            format.format(date),
+            Err(Error::MissingField(Field::Hour))
+        ));
+        let fields = Fields {
+            day: Some(Day::new(17).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            Format::new("day %d").unwrap().format(fields).unwrap(),
+            "day 17"
+        );
+    }
+}
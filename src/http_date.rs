@@ -0,0 +1,340 @@
+//! HTTP dates as defined by RFC 9110
+//!
+//! Header fields like `Last-Modified` and `Expires` contain an HTTP-date.
+//! Senders must use the IMF-fixdate format, `Sun, 06 Nov 1994 08:49:37 GMT`,
+//! but recipients must also accept the obsolete RFC 850 format,
+//! `Sunday, 06-Nov-94 08:49:37 GMT`, and the format of C's `asctime()`,
+//! `Sun Nov  6 08:49:37 1994`. All three are in UTC and case-sensitive. The
+//! day of the week must match the date.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::http_date::{format_http_date, parse_http_date};
+//! let dt = parse_http_date("Sun Nov  6 08:49:37 1994").unwrap();
+//! assert_eq!(dt.to_string(), "1994-11-06T08:49:37Z");
+//! let formatted = format_http_date(&dt).unwrap();
+//! assert_eq!(&formatted, b"Sun, 06 Nov 1994 08:49:37 GMT");
+//! ```
+
+use core::ops::{Deref, DerefMut};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    combined::{check_time, LocalDate, PreciseShiftedDateTime, ShiftedDateTime},
+    components::{self, Day, Hour, Minute, Month, Nanosecond, Second, Timeshift, Weekday, Year},
+    parse_utils,
+    rfc5322::{day_name, month_name, DAY_NAMES, MONTH_NAMES},
+};
+
+pub use crate::rfc5322::Error;
+
+/// Full names of the days of the week for the RFC 850 format, from Monday
+const LONG_DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Reads an HTTP-date from left to right
+struct Scanner<'a>(parse_utils::Scanner<'a>);
+
+impl<'a> Deref for Scanner<'a> {
+    type Target = parse_utils::Scanner<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Scanner<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Scanner<'_> {
+    /// The index of the name at the current position
+    fn name(&mut self, names: &[&str]) -> Result<usize, Error> {
+        let rest = self.rest();
+        let end = rest
+            .iter()
+            .position(|c| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        match names
+            .iter()
+            .position(|name| name.as_bytes() == &rest[..end])
+        {
+            Some(index) => {
+                self.advance(end);
+                Ok(index)
+            }
+            None => Err(self.error()),
+        }
+    }
+
+    /// `hh:mm:ss`
+    fn time(&mut self) -> Result<(u64, u64, u64), Error> {
+        let hour = self.number(2)?;
+        self.expect(b":")?;
+        let minute = self.number(2)?;
+        self.expect(b":")?;
+        let second = self.number(2)?;
+        Ok((hour, minute, second))
+    }
+}
+
+/// Parse an HTTP-date in any of the three formats
+///
+/// A two-digit year of the RFC 850 format is read as the year with those last
+/// two digits that is at most 50 years after the current year.
+pub fn parse_http_date(s: &str) -> Result<ShiftedDateTime, Error> {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let now = PreciseShiftedDateTime::from_unix_timestamp(seconds, Nanosecond::new(0)?)?;
+    parse_http_date_in(s, now.year)
+}
+
+/// Parse an HTTP-date, reading a two-digit year as at most 50 years after `current_year`
+pub fn parse_http_date_in(s: &str, current_year: Year) -> Result<ShiftedDateTime, Error> {
+    let mut scanner = Scanner(parse_utils::Scanner::new(s.as_bytes()));
+    let short_name = scanner.name(&DAY_NAMES);
+    let (weekday, day, month, year, (hour, minute, second)) = match short_name {
+        // IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+        Ok(weekday) if scanner.peek() == Some(b',') => {
+            scanner.expect(b", ")?;
+            let day = scanner.number(2)?;
+            scanner.expect(b" ")?;
+            let month = scanner.name(&MONTH_NAMES)?;
+            scanner.expect(b" ")?;
+            let year = scanner.number(4)? as i64;
+            scanner.expect(b" ")?;
+            let time = scanner.time()?;
+            scanner.expect(b" GMT")?;
+            (weekday, day, month, year, time)
+        }
+        // asctime: `Sun Nov  6 08:49:37 1994`
+        Ok(weekday) => {
+            scanner.expect(b" ")?;
+            let month = scanner.name(&MONTH_NAMES)?;
+            scanner.expect(b" ")?;
+            scanner.eat(b' ');
+            let day = match scanner.number(2) {
+                Ok(day) => day,
+                Err(_) => scanner.number(1)?,
+            };
+            scanner.expect(b" ")?;
+            let time = scanner.time()?;
+            scanner.expect(b" ")?;
+            let year = scanner.number(4)? as i64;
+            (weekday, day, month, year, time)
+        }
+        // RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+        Err(_) => {
+            let weekday = scanner.name(&LONG_DAY_NAMES)?;
+            scanner.expect(b", ")?;
+            let day = scanner.number(2)?;
+            scanner.expect(b"-")?;
+            let month = scanner.name(&MONTH_NAMES)?;
+            scanner.expect(b"-")?;
+            let year = full_year(scanner.number(2)?, current_year);
+            scanner.expect(b" ")?;
+            let time = scanner.time()?;
+            scanner.expect(b" GMT")?;
+            (weekday, day, month, year, time)
+        }
+    };
+    scanner.end()?;
+
+    let date = LocalDate::new(
+        Year::new(year.try_into().map_err(|_| components::Error::Range)?)?,
+        Month::new(month as u64 + 1)?,
+        Day::new(day)?,
+    );
+    if u8::from(date.day) > date.days_in_month() {
+        return Err(components::Error::Range.into());
+    }
+    if Weekday::new(weekday as u64 + 1)? != date.weekday() {
+        return Err(Error::Weekday);
+    }
+    if hour > 23 || minute > 59 {
+        return Err(components::Error::Range.into());
+    }
+    let (hour, minute, second) = (Hour::new(hour)?, Minute::new(minute)?, Second::new(second)?);
+    check_time(
+        hour,
+        minute,
+        second,
+        Nanosecond::new(0)?,
+        Some(Timeshift::Utc),
+    )?;
+    Ok(ShiftedDateTime::new(
+        date.year,
+        date.month,
+        date.day,
+        hour,
+        minute,
+        second,
+        Timeshift::Utc,
+    ))
+}
+
+/// The year ending in two digits that is at most 50 years after the current year
+fn full_year(two_digits: u64, current_year: Year) -> i64 {
+    let current_year = i64::from(current_year.value());
+    let two_digits = two_digits as i64;
+    let mut year = current_year - current_year % 100 + 100 + two_digits;
+    while year > current_year + 50 {
+        year -= 100;
+    }
+    year
+}
+
+/// Format a date-time as IMF-fixdate, like `Sun, 06 Nov 1994 08:49:37 GMT`
+///
+/// The date-time is converted to UTC first, which fails when that changes
+/// the year to one outside 0000 to 9999. The result is ASCII.
+pub fn format_http_date(date_time: &ShiftedDateTime) -> Result<[u8; 29], components::Error> {
+    let utc = PreciseShiftedDateTime::new(
+        date_time.year,
+        date_time.month,
+        date_time.day,
+        date_time.hour,
+        date_time.minute,
+        date_time.second,
+        Nanosecond::new(0)?,
+        date_time.timeshift,
+    )
+    .to_timeshift(Timeshift::Utc)?;
+    let date = LocalDate::new(utc.year, utc.month, utc.day);
+
+    let mut output = *b"Sun, 00 Jan 0000 00:00:00 GMT";
+    output[..3].copy_from_slice(day_name(date.weekday()).as_bytes());
+    write_digits(&mut output[5..7], u8::from(utc.day).into());
+    output[8..11].copy_from_slice(month_name(utc.month).as_bytes());
+    write_digits(&mut output[12..16], utc.year.value() as u32);
+    write_digits(&mut output[17..19], u8::from(utc.hour).into());
+    write_digits(&mut output[20..22], u8::from(utc.minute).into());
+    write_digits(&mut output[23..25], u8::from(utc.second).into());
+    Ok(output)
+}
+
+/// Write a number as decimal digits filling the buffer
+fn write_digits(buffer: &mut [u8], mut value: u32) {
+    for digit in buffer.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_http_date, parse_http_date, parse_http_date_in, Error};
+    use crate::{combined::ShiftedDateTime, components::Year};
+
+    #[test]
+    fn test_parse() {
+        let year = Year::new(2023).unwrap();
+        for s in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun Nov 06 08:49:37 1994",
+        ] {
+            let dt = parse_http_date_in(s, year);
+            assert!(dt.is_ok(), "{}: {:?}", s, dt);
+            assert_eq!(dt.unwrap().to_string(), "1994-11-06T08:49:37Z", "{}", s);
+        }
+        let dt = parse_http_date_in("Thu, 31 Dec 2015 23:59:60 GMT", year).unwrap();
+        assert_eq!(dt.to_string(), "2015-12-31T23:59:60Z");
+        let dt = parse_http_date("Tue, 15 Nov 1994 12:45:26 GMT").unwrap();
+        assert_eq!(dt.to_string(), "1994-11-15T12:45:26Z");
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        let parse = |s, year| {
+            parse_http_date_in(s, Year::new(year).unwrap())
+                .unwrap()
+                .year
+                .to_string()
+        };
+        assert_eq!(parse("Wednesday, 01-Jan-70 00:00:00 GMT", 2023), "2070");
+        assert_eq!(parse("Thursday, 01-Jan-70 00:00:00 GMT", 2019), "1970");
+        assert_eq!(parse("Saturday, 01-Jan-00 00:00:00 GMT", 2023), "2000");
+        assert_eq!(parse("Sunday, 01-Jan-73 00:00:00 GMT", 2023), "2073");
+        assert_eq!(parse("Tuesday, 01-Jan-74 00:00:00 GMT", 2023), "1974");
+        assert_eq!(parse("Saturday, 01-Jan-01 00:00:00 GMT", 2099), "2101");
+        assert_eq!(parse("Saturday, 01-Jan-50 00:00:00 GMT", 2099), "2050");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let year = Year::new(2023).unwrap();
+        for (s, position) in [
+            ("", 0),
+            ("sun, 06 Nov 1994 08:49:37 GMT", 0),
+            ("Sun, 06 nov 1994 08:49:37 GMT", 8),
+            ("Sun, 6 Nov 1994 08:49:37 GMT", 5),
+            ("Sun,  06 Nov 1994 08:49:37 GMT", 5),
+            ("Sun, 06 Nov 94 08:49:37 GMT", 12),
+            ("Sun, 06 Nov 1994 08:49 GMT", 22),
+            ("Sun, 06 Nov 1994 08:49:37 UTC", 25),
+            ("Sun, 06 Nov 1994 08:49:37 +0000", 25),
+            ("Sun, 06 Nov 1994 08:49:37 GMT ", 29),
+            ("Sun, 06-Nov-94 08:49:37 GMT", 7),
+            ("Sunday, 06 Nov 1994 08:49:37 GMT", 10),
+            ("Sunday, 06-Nov-1994 08:49:37 GMT", 17),
+            ("Sun Nov  6 08:49:37 94", 20),
+            ("Sun Nov   6 08:49:37 1994", 9),
+            ("Sun Nov  6 08:49:37 1994 GMT", 24),
+        ] {
+            let result = parse_http_date_in(s, year);
+            assert!(
+                matches!(result, Err(Error::Syntax { position: p }) if p == position),
+                "{}: {:?}",
+                s,
+                result
+            );
+        }
+        assert!(matches!(
+            parse_http_date_in("Mon, 06 Nov 1994 08:49:37 GMT", year),
+            Err(Error::Weekday)
+        ));
+        for s in [
+            "Tue, 31 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:00:00 GMT",
+            "Sun, 06 Nov 1994 08:60:37 GMT",
+            "Sun, 06 Nov 1994 08:49:60 GMT",
+        ] {
+            let result = parse_http_date_in(s, year);
+            assert!(
+                matches!(result, Err(Error::DateTime(_))),
+                "{}: {:?}",
+                s,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        for (s, expected) in [
+            ("1994-11-06T08:49:37Z", "Sun, 06 Nov 1994 08:49:37 GMT"),
+            ("1994-11-06T09:49:37+01:00", "Sun, 06 Nov 1994 08:49:37 GMT"),
+            ("2023-12-31T23:30:00-01:00", "Mon, 01 Jan 2024 00:30:00 GMT"),
+            ("0001-01-01T00:00:00Z", "Mon, 01 Jan 0001 00:00:00 GMT"),
+            ("2016-12-31T23:59:60Z", "Sat, 31 Dec 2016 23:59:60 GMT"),
+        ] {
+            let dt: ShiftedDateTime = s.parse().unwrap();
+            let formatted = format_http_date(&dt).unwrap();
+            assert_eq!(std::str::from_utf8(&formatted).unwrap(), expected);
+        }
+        let dt: ShiftedDateTime = "9999-12-31T23:30:00-01:00".parse().unwrap();
+        assert!(format_http_date(&dt).is_err());
+    }
+}
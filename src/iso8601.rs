@@ -0,0 +1,507 @@
+//! Detect which ISO 8601 representation a string uses
+//!
+//! [`parse_iso8601_any`](crate::parse_iso8601_any) accepts calendar, ordinal
+//! and week dates, times, date-times, durations and intervals, in basic or
+//! extended format, and tells which one it found. Inputs that ISO 8601 calls
+//! ambiguous are rejected instead of guessed:
+//!
+//! - `2023-260` is an ordinal date and `2023-09` a year and month, but the
+//!   basic `202309` is not allowed, as it could be a date with a two digit year.
+//! - A time without a date needs `:` separators or a leading `T`, since
+//!   `0908` is read as a year.
+//! - Basic and extended format can't be mixed in one time or date-time,
+//!   including its offset, so `09:08:58+0200` is rejected.
+
+use crate::{
+    calendar,
+    combined::{AnyDateTime, LocalDate, PreciseLocalTime},
+    components::{
+        Day, Error as ComponentError, Minute, Month, Nanosecond, Second, SimpleYear, Timeshift,
+        Week, Weekday, Year,
+    },
+    duration::Duration,
+    parse::{BuildError, Element, ParseContext, Parser},
+    parse_utils::{is_digit, parse_n_digits, tag, ParseError},
+    Error,
+};
+
+/// A value in one of the ISO 8601 representations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iso8601 {
+    /// `2023`
+    Year(Year),
+    /// `2023-09`
+    YearMonth {
+        year: Year,
+        month: Month,
+    },
+    /// `2023-09-17` or `20230917`
+    CalendarDate(LocalDate),
+    /// `2023-260` or `2023260`, converted to a calendar date
+    OrdinalDate(LocalDate),
+    /// `2023-W37-7` or `2023W377`, converted to a calendar date
+    WeekDate(LocalDate),
+    /// `2023-W37` or `2023W37`, with the year the week belongs to
+    Week {
+        year: Year,
+        week: Week,
+    },
+    /// `09:08:58.5`, `09:08` or `T0908`, with left out minutes and seconds as zero
+    Time(PreciseLocalTime),
+    /// A time with `Z` or an offset, like `09:08:58Z` or `T0908+0200`
+    ShiftedTime {
+        time: PreciseLocalTime,
+        timeshift: Timeshift,
+    },
+    /// A date in any of the formats above followed by `T` and a time
+    DateTime(AnyDateTime),
+    /// `P1Y2M3DT4H5M6S`
+    Duration(Duration),
+    Interval(Interval),
+}
+
+/// A start or end of an [`Interval`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Point {
+    Date(LocalDate),
+    DateTime(AnyDateTime),
+}
+
+/// An interval like `2023-09-17T09:00Z/PT1H`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    StartEnd { start: Point, end: Point },
+    StartDuration { start: Point, duration: Duration },
+    DurationEnd { duration: Duration, end: Point },
+}
+
+/// The date part of a value, before any `T`
+enum DatePart {
+    Year(Year),
+    YearMonth(Year, Month),
+    Calendar(LocalDate),
+    Ordinal(LocalDate),
+    Week(Year, Week),
+    WeekDate(LocalDate),
+}
+
+impl DatePart {
+    fn date(&self) -> Option<LocalDate> {
+        match *self {
+            DatePart::Calendar(date) | DatePart::Ordinal(date) | DatePart::WeekDate(date) => {
+                Some(date)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Exactly `n` ASCII digits
+fn digits(n: usize, data: &[u8]) -> Result<(u64, &[u8]), ParseError<'_>> {
+    match data.get(..n) {
+        Some(head) if head.iter().all(|&c| is_digit(c)) => parse_n_digits(n, data),
+        Some(_) => Err(ParseError::Fail(data)),
+        None => Err(ParseError::UnexpectedEof { needed: n }),
+    }
+}
+
+fn digit_run(data: &[u8]) -> usize {
+    data.iter().take_while(|&&c| is_digit(c)).count()
+}
+
+fn invalid<'a>(error: ComponentError) -> Error<'a> {
+    BuildError::Invalid(error).into()
+}
+
+fn date_from_days<'a>(days: i64) -> Result<LocalDate, Error<'a>> {
+    LocalDate::from_days_since_epoch(days).map_err(invalid)
+}
+
+/// Parse a date, returning whether it used the extended format
+fn parse_date(data: &[u8]) -> Result<(DatePart, bool), Error<'_>> {
+    let (year, rest) = digits(4, data)?;
+    let year = Year::new(year as i32).map_err(invalid)?;
+    let (extended, rest) = match rest.first() {
+        None => return Ok((DatePart::Year(year), false)),
+        Some(b'-') => (true, &rest[1..]),
+        Some(_) => (false, rest),
+    };
+    let (part, rest) = if let Some(rest) = rest.strip_prefix(b"W") {
+        let (week, rest) = digits(2, rest)?;
+        let week = Week::new(week).map_err(ParseError::from)?;
+        let weeks_in_year = calendar::iso_weeks_in_year(year.value());
+        if u8::from(week) > weeks_in_year {
+            return Err(invalid(ComponentError::Range));
+        }
+        let rest = match (extended, rest.is_empty()) {
+            (_, true) => return Ok((DatePart::Week(year, week), extended)),
+            (true, false) => tag(b"-")(rest)?.1,
+            (false, false) => rest,
+        };
+        let (weekday, rest) = digits(1, rest)?;
+        let weekday: i64 = Weekday::new(weekday).map_err(ParseError::from)?.into();
+        let january_fourth = calendar::days_from_civil(year.value(), 1, 4);
+        let first_monday = january_fourth - (january_fourth + 3).rem_euclid(7);
+        let days = first_monday + (i64::from(u8::from(week)) - 1) * 7 + weekday - 1;
+        (DatePart::WeekDate(date_from_days(days)?), rest)
+    } else {
+        match (extended, digit_run(rest)) {
+            (_, 3) => {
+                let (day, rest) = digits(3, rest)?;
+                let days_in_year = if calendar::is_leap_year(year.value()) {
+                    366
+                } else {
+                    365
+                };
+                if !(1..=days_in_year).contains(&day) {
+                    return Err(invalid(ComponentError::Range));
+                }
+                let days = calendar::days_from_civil(year.value(), 1, 1) + day as i64 - 1;
+                (DatePart::Ordinal(date_from_days(days)?), rest)
+            }
+            (true, 2) if rest.len() == 2 => {
+                let (month, rest) = digits(2, rest)?;
+                let month = Month::new(month).map_err(ParseError::from)?;
+                (DatePart::YearMonth(year, month), rest)
+            }
+            (true, 2) | (false, 4) => {
+                let (month, rest) = digits(2, rest)?;
+                let rest = match extended {
+                    true => tag(b"-")(rest)?.1,
+                    false => rest,
+                };
+                let (day, rest) = digits(2, rest)?;
+                let month = Month::new(month).map_err(ParseError::from)?;
+                let day = Day::new(day).map_err(ParseError::from)?;
+                if u8::from(day) > year.days_in_month(month) {
+                    return Err(invalid(ComponentError::Range));
+                }
+                (DatePart::Calendar(LocalDate::new(year, month, day)), rest)
+            }
+            _ => return Err(ParseError::Fail(rest).into()),
+        }
+    };
+    match rest {
+        [] => Ok((part, extended)),
+        _ => Err(ParseError::Fail(rest).into()),
+    }
+}
+
+/// Parse a time after the date, or on its own when `date` is `None`
+///
+/// Minutes and seconds can be left out from the end. When `extended` is
+/// `None` the format is taken from the time itself.
+fn parse_time(
+    date: Option<LocalDate>,
+    extended: Option<bool>,
+    data: &[u8],
+) -> Result<Parser, Error<'_>> {
+    let mut parser = ParseContext::new_iso8601().into_parser();
+    if let Some(date) = date {
+        parser.push_element(Element::Year(date.year));
+        parser.push_element(Element::Month(date.month));
+        parser.push_element(Element::Day(date.day));
+    }
+    let mut rest = parser.parse_hour(data)?;
+    let extended = extended.unwrap_or(rest.first() == Some(&b':'));
+    let mut components = 1;
+    while components < 3 {
+        let after_separator = match extended {
+            true => match tag(b":")(rest) {
+                Ok((_, after)) => after,
+                Err(_) => break,
+            },
+            false => rest,
+        };
+        if !after_separator.first().is_some_and(|&c| is_digit(c)) {
+            if extended {
+                return Err(ParseError::Fail(rest).into());
+            }
+            break;
+        }
+        rest = match components {
+            1 => parser.parse_minute(after_separator)?,
+            _ => parser.parse_second(after_separator)?,
+        };
+        components += 1;
+    }
+    // A fraction is only supported on the seconds
+    let mut precise = false;
+    if components == 3 {
+        if let Ok(after) = parser.parse_fractional_separator(rest) {
+            rest = parser.parse_fractional_seconds(after)?;
+            precise = true;
+        }
+    }
+    let zero_minute = Minute::new(0).map_err(ParseError::from)?;
+    let zero_second = Second::new(0).map_err(ParseError::from)?;
+    if components < 2 {
+        parser.push_element(Element::Minute(zero_minute));
+    }
+    if components < 3 {
+        parser.push_element(Element::Second(zero_second));
+    }
+    if date.is_none() && !precise {
+        parser.push_element(Element::Nanosecond(
+            Nanosecond::new(0).map_err(ParseError::from)?,
+        ));
+    }
+    // The offset follows the format of the time, `+02:00` or `+0200`
+    if let [b'+' | b'-', _, _, c, ..] = rest {
+        if (*c == b':') != extended {
+            return Err(ParseError::Fail(rest).into());
+        }
+    }
+    rest = parser.parse_optional_timezone_offset(rest)?;
+    match rest {
+        [] => Ok(parser),
+        _ => Err(ParseError::Fail(rest).into()),
+    }
+}
+
+/// A time without a date, which is shifted when it has an offset
+fn time_of_day(parser: Parser) -> Result<Iso8601, BuildError<SimpleYear>> {
+    Ok(match parser.build_precise_time_with_offset()? {
+        (time, None) => Iso8601::Time(time),
+        (time, Some(timeshift)) => Iso8601::ShiftedTime { time, timeshift },
+    })
+}
+
+fn parse_point(data: &[u8]) -> Result<Point, Error<'_>> {
+    match parse_date_or_date_time(data)? {
+        Iso8601::CalendarDate(date) | Iso8601::OrdinalDate(date) | Iso8601::WeekDate(date) => {
+            Ok(Point::Date(date))
+        }
+        Iso8601::DateTime(date_time) => Ok(Point::DateTime(date_time)),
+        _ => Err(ParseError::Fail(data).into()),
+    }
+}
+
+fn parse_duration(data: &[u8]) -> Result<Duration, Error<'_>> {
+    let duration = core::str::from_utf8(data).map_err(ParseError::from)?;
+    Ok(duration
+        .parse::<Duration>()
+        .map_err(|_| ParseError::Fail(data))?)
+}
+
+fn parse_date_or_date_time(data: &[u8]) -> Result<Iso8601, Error<'_>> {
+    let Some(t) = data.iter().position(|&c| c == b'T') else {
+        return Ok(match parse_date(data)?.0 {
+            DatePart::Year(year) => Iso8601::Year(year),
+            DatePart::YearMonth(year, month) => Iso8601::YearMonth { year, month },
+            DatePart::Calendar(date) => Iso8601::CalendarDate(date),
+            DatePart::Ordinal(date) => Iso8601::OrdinalDate(date),
+            DatePart::Week(year, week) => Iso8601::Week { year, week },
+            DatePart::WeekDate(date) => Iso8601::WeekDate(date),
+        });
+    };
+    let (date, extended) = parse_date(&data[..t])?;
+    let date = date.date().ok_or(ParseError::Fail(data))?;
+    let parser = parse_time(Some(date), Some(extended), &data[t + 1..])?;
+    Ok(Iso8601::DateTime(parser.build_any_date_time()?))
+}
+
+pub(crate) fn parse_any(data: &[u8]) -> Result<Iso8601, Error<'_>> {
+    if let Some(slash) = data.iter().position(|&c| c == b'/') {
+        let (start, end) = (&data[..slash], &data[slash + 1..]);
+        let interval = match (start.first(), end.first()) {
+            (Some(b'P'), Some(b'P')) => return Err(ParseError::Fail(end).into()),
+            (Some(b'P'), _) => Interval::DurationEnd {
+                duration: parse_duration(start)?,
+                end: parse_point(end)?,
+            },
+            (_, Some(b'P')) => Interval::StartDuration {
+                start: parse_point(start)?,
+                duration: parse_duration(end)?,
+            },
+            _ => Interval::StartEnd {
+                start: parse_point(start)?,
+                end: parse_point(end)?,
+            },
+        };
+        return Ok(Iso8601::Interval(interval));
+    }
+    match data {
+        [b'P', ..] | [b'-', b'P', ..] => Ok(Iso8601::Duration(parse_duration(data)?)),
+        [b'T', time @ ..] => Ok(time_of_day(parse_time(None, None, time)?)?),
+        [_, _, b':', ..] => Ok(time_of_day(parse_time(None, Some(true), data)?)?),
+        _ => parse_date_or_date_time(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, Iso8601, Point};
+    use crate::{parse_iso8601_any, AnyDateTime};
+
+    fn date(s: &str) -> String {
+        match parse_iso8601_any(s).unwrap() {
+            Iso8601::CalendarDate(date) | Iso8601::OrdinalDate(date) | Iso8601::WeekDate(date) => {
+                date.to_string()
+            }
+            other => panic!("{}: {:?}", s, other),
+        }
+    }
+
+    #[test]
+    fn test_dates() {
+        assert!(matches!(
+            parse_iso8601_any("2023-09-17"),
+            Ok(Iso8601::CalendarDate(_))
+        ));
+        assert!(matches!(
+            parse_iso8601_any("2023-260"),
+            Ok(Iso8601::OrdinalDate(_))
+        ));
+        assert!(matches!(
+            parse_iso8601_any("2023-09"),
+            Ok(Iso8601::YearMonth { .. })
+        ));
+        assert!(matches!(parse_iso8601_any("2023"), Ok(Iso8601::Year(_))));
+        assert!(matches!(
+            parse_iso8601_any("2023-W37"),
+            Ok(Iso8601::Week { .. })
+        ));
+        assert_eq!(date("20230917"), "2023-09-17");
+        assert_eq!(date("2023-260"), "2023-09-17");
+        assert_eq!(date("2023260"), "2023-09-17");
+        assert_eq!(date("2024-366"), "2024-12-31");
+        assert_eq!(date("2023-W37-7"), "2023-09-17");
+        assert_eq!(date("2023W377"), "2023-09-17");
+        assert_eq!(date("2020-W01-1"), "2019-12-30");
+        assert_eq!(date("2020-W53-5"), "2021-01-01");
+
+        for s in [
+            "202309",
+            "2023-0917",
+            "202309-17",
+            "2023-366",
+            "2023-000",
+            "2023-W53-1",
+            "2023-W37-8",
+            "2023W37-7",
+            "2023-02-29",
+            "2023-13",
+            "2023-09-17Z",
+            "23-09-17",
+            "+2023",
+        ] {
+            assert!(parse_iso8601_any(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_times() {
+        let time = |s: &str| match parse_iso8601_any(s) {
+            Ok(Iso8601::Time(time)) => time.to_string(),
+            other => panic!("{}: {:?}", s, other),
+        };
+        assert_eq!(time("09:08:58"), "09:08:58.0");
+        assert_eq!(time("09:08:58.5"), "09:08:58.5");
+        assert_eq!(time("09:08"), "09:08:00.0");
+        assert_eq!(time("T090858"), "09:08:58.0");
+        assert_eq!(time("T0908"), "09:08:00.0");
+        assert_eq!(time("T09"), "09:00:00.0");
+        assert_eq!(time("T09:08"), "09:08:00.0");
+
+        let shifted_time = |s: &str| match parse_iso8601_any(s) {
+            Ok(Iso8601::ShiftedTime { time, timeshift }) => format!("{}{}", time, timeshift),
+            other => panic!("{}: {:?}", s, other),
+        };
+        assert_eq!(shifted_time("09:08:58Z"), "09:08:58.0Z");
+        assert_eq!(shifted_time("09:08:58.5+02:00"), "09:08:58.5+02:00");
+        assert_eq!(shifted_time("T09:08Z"), "09:08:00.0Z");
+        assert_eq!(shifted_time("T0908+0200"), "09:08:00.0+02:00");
+        assert_eq!(shifted_time("T09-0530"), "09:00:00.0-05:30");
+        assert_eq!(shifted_time("23:59:60Z"), "23:59:60.0Z");
+
+        for s in [
+            "0908",
+            "09:0858",
+            "09:08:",
+            "09:08.5",
+            "09:08:58+0200",
+            "T0908+02:00",
+            "09:08:58+02",
+            "09:08:60+02:00",
+            "09:08:58ZZ",
+        ] {
+            assert!(
+                !matches!(
+                    parse_iso8601_any(s),
+                    Ok(Iso8601::Time(_) | Iso8601::ShiftedTime { .. })
+                ),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_times() {
+        let date_time = |s: &str| match parse_iso8601_any(s) {
+            Ok(Iso8601::DateTime(date_time)) => date_time,
+            other => panic!("{}: {:?}", s, other),
+        };
+        let dt = date_time("2023-09-17T09:08:58Z");
+        assert!(matches!(dt, AnyDateTime::Shifted(_)));
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58Z");
+        let dt = date_time("20230917T090858.5+0200");
+        assert!(matches!(dt, AnyDateTime::PreciseShifted(_)));
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58.5+02:00");
+        assert_eq!(
+            date_time("2023-09-17T09:08:58-05:30").to_string(),
+            "2023-09-17T09:08:58-05:30"
+        );
+        let dt = date_time("2023-260T09:08");
+        assert!(matches!(dt, AnyDateTime::Local(_)));
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:00");
+        assert_eq!(
+            date_time("2023-W37-7T09:08:58").to_string(),
+            "2023-09-17T09:08:58"
+        );
+        for s in [
+            "2023-09-17T090858",
+            "20230917T09:08:58",
+            "2023-09T09:08:58",
+            "2023-09-17T",
+            "2023-09-17T09:08:58 ",
+            "2023-09-17T09:08:58+0200",
+            "20230917T090858+02:00",
+        ] {
+            assert!(parse_iso8601_any(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_durations_and_intervals() {
+        assert!(matches!(
+            parse_iso8601_any("P1Y2M3DT4H5M6S"),
+            Ok(Iso8601::Duration(_))
+        ));
+        assert!(matches!(
+            parse_iso8601_any("2023-09-17T09:00Z/PT1H"),
+            Ok(Iso8601::Interval(Interval::StartDuration {
+                start: Point::DateTime(_),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            parse_iso8601_any("P1D/2023-09-17"),
+            Ok(Iso8601::Interval(Interval::DurationEnd {
+                end: Point::Date(_),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            parse_iso8601_any("2023-09-01/2023-W38-1T12:00:00Z"),
+            Ok(Iso8601::Interval(Interval::StartEnd {
+                start: Point::Date(_),
+                end: Point::DateTime(_),
+            }))
+        ));
+        for s in ["P1D/P2D", "2023-09/2023-10", "2023-09-17/", "PX", "P"] {
+            assert!(parse_iso8601_any(s).is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Internet Extended Date/Time Format as defined by RFC 9557
+//!
+//! RFC 9557 adds suffixes in brackets to an RFC 3339 date-time, for a time
+//! zone and for tags such as the calendar:
+//! `2023-09-17T11:08:58+02:00[Europe/Amsterdam][u-ca=gregory]`.
+//!
+//! A suffix starting with `!` is critical. The parser rejects critical
+//! suffixes that contradict the date-time or each other, but it does not know
+//! which tag keys an application understands. Applications must reject a
+//! critical tag with a key they do not support, see
+//! [`AnnotatedDateTime::critical_tags`].
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::ixdtf::{AnnotatedDateTime, TimeZone};
+//! let dt: AnnotatedDateTime =
+//!     "2023-09-17T11:08:58+02:00[Europe/Amsterdam][u-ca=gregory]".parse().unwrap();
+//! assert_eq!(dt.tag("u-ca"), Some("gregory"));
+//! assert_eq!(
+//!     dt.time_zone.unwrap().zone,
+//!     TimeZone::Name("Europe/Amsterdam".to_string())
+//! );
+//! ```
+
+use core::{fmt, str};
+
+use crate::{
+    combined::PreciseShiftedDateTime,
+    components::{self, Hour, Minute, SimpleYear, Timeshift, YearDigits},
+    parse::{parse_prefix, ParseContext, Parser},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The RFC 3339 date-time before the suffixes is invalid
+    DateTime(components::Error),
+    /// A suffix does not follow the RFC 9557 syntax, starting at this byte
+    Syntax { position: usize },
+    /// A critical suffix contradicts the date-time or another suffix
+    Inconsistent,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DateTime(e) => write!(f, "invalid date-time: {}", e),
+            Error::Syntax { position } => write!(f, "invalid suffix at byte {}", position),
+            Error::Inconsistent => write!(f, "critical suffix is inconsistent"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DateTime(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::DateTime(value)
+    }
+}
+
+/// The time zone of a time zone suffix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeZone {
+    /// A time zone name, usually from the IANA time zone database
+    Name(String),
+    /// A fixed offset, written as `+00:00` when it is zero
+    Offset(Timeshift),
+}
+
+/// A time zone suffix like `[Europe/Amsterdam]` or `[!+02:00]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZoneAnnotation {
+    pub critical: bool,
+    pub zone: TimeZone,
+}
+
+/// A tag suffix like `[u-ca=gregory]`
+///
+/// The value can consist of several parts separated by `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub critical: bool,
+    pub key: String,
+    pub value: String,
+}
+
+/// An RFC 3339 date-time with RFC 9557 suffixes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedDateTime<Y = SimpleYear> {
+    pub date_time: PreciseShiftedDateTime<Y>,
+    pub time_zone: Option<TimeZoneAnnotation>,
+    /// The tags in the order they were written, including repeated keys
+    pub tags: Vec<Tag>,
+}
+
+impl<Y> AnnotatedDateTime<Y> {
+    pub fn new(date_time: PreciseShiftedDateTime<Y>) -> Self {
+        Self {
+            date_time,
+            time_zone: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// The value of the first tag with this key
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|tag| tag.key == key)
+            .map(|tag| tag.value.as_str())
+    }
+
+    /// The tags marked with `!`, which must be rejected when not understood
+    pub fn critical_tags(&self) -> impl Iterator<Item = &Tag> {
+        self.tags.iter().filter(|tag| tag.critical)
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZone::Name(name) => write!(f, "{}", name),
+            // The `Z` of RFC 3339 is not allowed in a suffix
+            TimeZone::Offset(Timeshift::Utc) => write!(f, "+00:00"),
+            TimeZone::Offset(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl fmt::Display for TimeZoneAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = if self.critical { "!" } else { "" };
+        write!(f, "[{}{}]", flag, self.zone)
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = if self.critical { "!" } else { "" };
+        write!(f, "[{}{}={}]", flag, self.key, self.value)
+    }
+}
+
+impl<Y> fmt::Display for AnnotatedDateTime<Y>
+where
+    PreciseShiftedDateTime<Y>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.date_time)?;
+        if let Some(time_zone) = &self.time_zone {
+            write!(f, "{}", time_zone)?;
+        }
+        for tag in &self.tags {
+            write!(f, "{}", tag)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_time_zone_initial(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'.' || c == b'_'
+}
+
+fn is_time_zone_char(c: u8) -> bool {
+    is_time_zone_initial(c) || c.is_ascii_digit() || c == b'-' || c == b'+'
+}
+
+fn is_time_zone_name(name: &str) -> bool {
+    name.split('/').all(|part| match part.as_bytes() {
+        b"" | b"." | b".." => false,
+        [first, rest @ ..] => {
+            is_time_zone_initial(*first) && rest.iter().all(|&c| is_time_zone_char(c))
+        }
+    })
+}
+
+/// Parse a `time-numoffset`, which unlike an RFC 3339 offset can't be `Z`
+fn parse_offset(s: &str) -> Option<Timeshift> {
+    let (non_negative, rest) = match s.as_bytes().first()? {
+        b'+' => (true, &s[1..]),
+        b'-' => (false, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = rest.split_once(':')?;
+    Some(Timeshift::offset(
+        non_negative,
+        hours.parse::<Hour>().ok().filter(|h| u8::from(*h) < 24)?,
+        minutes.parse::<Minute>().ok()?,
+    ))
+}
+
+fn is_key(key: &str) -> bool {
+    match key.as_bytes() {
+        [first, rest @ ..] => {
+            (first.is_ascii_lowercase() || *first == b'_')
+                && rest.iter().all(|&c| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_' || c == b'-'
+                })
+        }
+        [] => false,
+    }
+}
+
+fn is_value(value: &str) -> bool {
+    value
+        .split('-')
+        .all(|part| !part.is_empty() && part.bytes().all(|c| c.is_ascii_alphanumeric()))
+}
+
+impl<Y: YearDigits> str::FromStr for AnnotatedDateTime<Y> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date_time, mut rest) = parse_prefix(
+            ParseContext::new_rfc3339().with_year_sign(),
+            s,
+            Parser::<Y>::parse_precise_shifted_date_time,
+            Parser::<Y>::build_precise_shifted_date_time,
+        )?;
+        let mut result = Self::new(date_time);
+        while !rest.is_empty() {
+            let position = s.len() - rest.len();
+            let syntax = Error::Syntax { position };
+            let end = match rest.strip_prefix('[') {
+                Some(suffix) => suffix.find(']').ok_or(Error::Syntax { position })?,
+                None => return Err(syntax),
+            };
+            let suffix = &rest[1..end + 1];
+            rest = &rest[end + 2..];
+            let (critical, suffix) = match suffix.strip_prefix('!') {
+                Some(suffix) => (true, suffix),
+                None => (false, suffix),
+            };
+
+            if let Some((key, value)) = suffix.split_once('=') {
+                if !is_key(key) || !is_value(value) {
+                    return Err(syntax);
+                }
+                let repeated = result.tags.iter().filter(|tag| tag.key == key);
+                if repeated.clone().next().is_some()
+                    && (critical || repeated.clone().any(|tag| tag.critical))
+                {
+                    return Err(Error::Inconsistent);
+                }
+                result.tags.push(Tag {
+                    critical,
+                    key: key.to_string(),
+                    value: value.to_string(),
+                });
+                continue;
+            }
+
+            // The time zone can only be the first suffix
+            if result.time_zone.is_some() || !result.tags.is_empty() {
+                return Err(syntax);
+            }
+            let zone = if suffix.starts_with(['+', '-']) {
+                TimeZone::Offset(parse_offset(suffix).ok_or(syntax)?)
+            } else if is_time_zone_name(suffix) {
+                TimeZone::Name(suffix.to_string())
+            } else {
+                return Err(syntax);
+            };
+            if let (TimeZone::Offset(offset), Timeshift::Offset { .. }) =
+                (&zone, result.date_time.timeshift)
+            {
+                if critical
+                    && offset.seconds_from_east() != result.date_time.timeshift.seconds_from_east()
+                {
+                    return Err(Error::Inconsistent);
+                }
+            }
+            result.time_zone = Some(TimeZoneAnnotation { critical, zone });
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnnotatedDateTime, Error, Tag, TimeZone, TimeZoneAnnotation};
+    use crate::components::{ExtendedYear, Hour, Minute, SimpleYear, Timeshift};
+
+    #[test]
+    fn test_parse() {
+        let dt: AnnotatedDateTime = "2023-09-17T11:08:58+02:00[Europe/Amsterdam][u-ca=gregory]"
+            .parse()
+            .unwrap();
+        assert_eq!(dt.date_time, "2023-09-17T11:08:58+02:00".parse().unwrap());
+        assert_eq!(
+            dt.time_zone,
+            Some(TimeZoneAnnotation {
+                critical: false,
+                zone: TimeZone::Name("Europe/Amsterdam".to_string()),
+            })
+        );
+        assert_eq!(
+            dt.tags,
+            vec![Tag {
+                critical: false,
+                key: "u-ca".to_string(),
+                value: "gregory".to_string(),
+            }]
+        );
+
+        let dt: AnnotatedDateTime = "2023-09-17T09:08:58Z[!-05:30][_x-y=a-b1][u-ca=iso8601]"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            dt.time_zone.as_ref().unwrap().zone,
+            TimeZone::Offset(Timeshift::negative_offset(
+                Hour::new(5).unwrap(),
+                Minute::new(30).unwrap()
+            ))
+        );
+        assert_eq!(dt.tag("_x-y"), Some("a-b1"));
+        assert_eq!(dt.critical_tags().count(), 0);
+
+        let dt: AnnotatedDateTime = "2023-09-17T09:08:58.5Z[u-ca=japanese][u-ca=gregory]"
+            .parse()
+            .unwrap();
+        assert_eq!(dt.tag("u-ca"), Some("japanese"));
+        assert_eq!(dt.tags.len(), 2);
+
+        let dt: AnnotatedDateTime<ExtendedYear<6>> = "+012023-09-17T09:08:58Z[Etc/GMT+1][!foo=bar]"
+            .parse()
+            .unwrap();
+        assert_eq!(dt.critical_tags().next().unwrap().key, "foo");
+        assert!(dt.time_zone.is_some());
+    }
+
+    #[test]
+    fn test_format() {
+        for s in [
+            "2023-09-17T11:08:58.0+02:00[Europe/Amsterdam][u-ca=gregory]",
+            "2023-09-17T09:08:58.5Z[!+00:00]",
+            "2023-09-17T09:08:58.0Z[u-ca=gregory][!_foo=bar-baz]",
+            "2023-09-17T09:08:58.0Z",
+        ] {
+            let dt: AnnotatedDateTime = s.parse().unwrap();
+            assert_eq!(dt.to_string(), s);
+        }
+        let mut dt = AnnotatedDateTime::<SimpleYear>::new("2023-09-17T09:08:58Z".parse().unwrap());
+        dt.time_zone = Some(TimeZoneAnnotation {
+            critical: true,
+            zone: TimeZone::Offset(Timeshift::Utc),
+        });
+        assert_eq!(dt.to_string(), "2023-09-17T09:08:58.0Z[!+00:00]");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let syntax = |s: &str| match s.parse::<AnnotatedDateTime>() {
+            Err(Error::Syntax { position }) => position,
+            other => panic!("{} gave {:?}", s, other),
+        };
+        assert_eq!(syntax("2023-09-17T09:08:58Z[Europe/Amsterdam"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[Europe//Amsterdam]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[Europe/..]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[1Europe]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[UTC]x"), 25);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[+2:00]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[+24:00]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[u-ca=gregory][UTC]"), 34);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[UTC][UTC]"), 25);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[U-CA=gregory]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[u-ca=]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[u-ca=greg--ory]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[u-ca=greg_ory]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z[!!UTC]"), 20);
+        assert_eq!(syntax("2023-09-17T09:08:58Z [UTC]"), 20);
+
+        assert!(matches!(
+            "2023-09-17T09:08:58+01:00[!+02:00]".parse::<AnnotatedDateTime>(),
+            Err(Error::Inconsistent)
+        ));
+        assert!("2023-09-17T09:08:58+01:00[+02:00]"
+            .parse::<AnnotatedDateTime>()
+            .is_ok());
+        assert!(matches!(
+            "2023-09-17T09:08:58Z[u-ca=gregory][!u-ca=japanese]".parse::<AnnotatedDateTime>(),
+            Err(Error::Inconsistent)
+        ));
+        assert!(matches!(
+            "2023-09-17T25:08:58Z[UTC]".parse::<AnnotatedDateTime>(),
+            Err(Error::DateTime(_))
+        ));
+    }
+}
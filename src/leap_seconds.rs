@@ -0,0 +1,565 @@
+//! Leap seconds and the TAI and GPS time scales
+//!
+//! UTC follows TAI, the international atomic time, at an offset of a whole
+//! number of seconds since 1972. Every leap second increases that offset by
+//! one. GPS time runs at a fixed 19 seconds behind TAI.
+//!
+//! Leap seconds are announced about six months ahead, so a leap second list
+//! expires. Conversions after the expiration of a [`LeapSecondTable`] keep
+//! using its last offset, which is wrong once a new leap second is inserted.
+//! Compare with [`LeapSecondTable::expires`] to detect this.
+
+use core::fmt;
+use std::{fs, io, path::Path};
+
+use crate::{
+    combined::PreciseShiftedDateTime,
+    components::{self, Nanosecond, Second, YearDigits},
+};
+
+/// Seconds between the NTP epoch 1900-01-01 and the Unix epoch 1970-01-01
+const NTP_UNIX_OFFSET: i64 = 2_208_988_800;
+
+/// Seconds between the Unix epoch 1970-01-01 and the GPS epoch 1980-01-06
+const GPS_EPOCH: i64 = 315_964_800;
+
+/// Difference between TAI and GPS time in seconds
+const TAI_MINUS_GPS: i64 = 19;
+
+/// The IERS leap second list as known at release, in NTP seconds and TAI - UTC
+const BUILTIN: [(i64, i32); 28] = [
+    (2272060800, 10),
+    (2287785600, 11),
+    (2303683200, 12),
+    (2335219200, 13),
+    (2366755200, 14),
+    (2398291200, 15),
+    (2429913600, 16),
+    (2461449600, 17),
+    (2492985600, 18),
+    (2524521600, 19),
+    (2571782400, 20),
+    (2603318400, 21),
+    (2634854400, 22),
+    (2698012800, 23),
+    (2776982400, 24),
+    (2840140800, 25),
+    (2871676800, 26),
+    (2918937600, 27),
+    (2950473600, 28),
+    (2982009600, 29),
+    (3029443200, 30),
+    (3076704000, 31),
+    (3124137600, 32),
+    (3345062400, 33),
+    (3439756800, 34),
+    (3550089600, 35),
+    (3644697600, 36),
+    (3692217600, 37),
+];
+
+/// Expiration of the built-in list, 2026-12-28, in NTP seconds
+const BUILTIN_EXPIRES: i64 = 4007404800;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// A line of a leap second list could not be parsed
+    Parse {
+        line: usize,
+    },
+    /// The list has no entries, they are not in chronological order, or
+    /// TAI - UTC does not increase by one second at every entry
+    InvalidTable,
+    /// The `#h` hash of the list does not match its contents
+    Hash,
+    /// The date-time is before 1972, when UTC had no whole second offset to TAI
+    BeforeTable,
+    /// Second 60 was used at a time no leap second was scheduled
+    NotALeapSecond,
+    Component(components::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read leap second list: {}", e),
+            Error::Parse { line } => write!(f, "invalid leap second list at line {}", line),
+            Error::InvalidTable => write!(f, "leap second list is not a valid table"),
+            Error::Hash => write!(f, "leap second list does not match its hash"),
+            Error::BeforeTable => write!(f, "date-time is before the first leap second entry"),
+            Error::NotALeapSecond => write!(f, "no leap second at this time"),
+            Error::Component(e) => write!(f, "invalid date-time: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Component(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::Component(value)
+    }
+}
+
+/// A point in time on the TAI time scale
+///
+/// Counted in seconds since 1970-01-01T00:00:00 TAI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TaiTime {
+    pub seconds: i64,
+    pub nanosecond: Nanosecond,
+}
+
+/// A point in time on the GPS time scale
+///
+/// Counted in seconds since the GPS epoch, 1980-01-06T00:00:00Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GpsTime {
+    pub seconds: i64,
+    pub nanosecond: Nanosecond,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    /// Unix timestamp from which the offset applies
+    since: i64,
+    tai_minus_utc: i32,
+}
+
+/// The offsets between TAI and UTC over time
+///
+/// [`LeapSecondTable::default`] contains the leap seconds known when this
+/// crate was released. Newer lists can be loaded from the IERS
+/// `leap-seconds.list` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSecondTable {
+    entries: Vec<Entry>,
+    expires: Option<i64>,
+}
+
+impl LeapSecondTable {
+    pub fn builtin() -> Self {
+        Self {
+            entries: BUILTIN
+                .iter()
+                .map(|&(ntp, tai_minus_utc)| Entry {
+                    since: ntp - NTP_UNIX_OFFSET,
+                    tai_minus_utc,
+                })
+                .collect(),
+            expires: Some(BUILTIN_EXPIRES - NTP_UNIX_OFFSET),
+        }
+    }
+
+    /// Parse the contents of an IERS `leap-seconds.list` file
+    ///
+    /// When the file has a `#h` line, its SHA-1 hash of the update time, the
+    /// expiration and the entries must match.
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let mut entries = Vec::new();
+        let mut expires = None;
+        let mut hash = None;
+        // The hashed text, which is the values without any whitespace
+        let mut updated = String::new();
+        let mut expiration = String::new();
+        let mut data_values = String::new();
+        for (idx, line) in contents.lines().enumerate() {
+            let invalid = || Error::Parse { line: idx + 1 };
+            if let Some(value) = line.strip_prefix("#$") {
+                updated = value.trim().to_string();
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("#@") {
+                let ntp: i64 = value.trim().parse().map_err(|_| invalid())?;
+                expires = Some(ntp - NTP_UNIX_OFFSET);
+                expiration = value.trim().to_string();
+                continue;
+            }
+            if let Some(value) = line.strip_prefix("#h") {
+                let words: Vec<u32> = value
+                    .split_whitespace()
+                    .map(|word| u32::from_str_radix(word, 16))
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| invalid())?;
+                hash = Some(<[u32; 5]>::try_from(words).map_err(|_| invalid())?);
+                continue;
+            }
+            let data = match line.find('#') {
+                Some(comment) => &line[..comment],
+                None => line,
+            };
+            let mut fields = data.split_whitespace();
+            let (ntp, tai_minus_utc) = match (fields.next(), fields.next(), fields.next()) {
+                (None, _, _) => continue,
+                (Some(ntp), Some(tai_minus_utc), None) => (ntp, tai_minus_utc),
+                _ => return Err(invalid()),
+            };
+            data_values.push_str(ntp);
+            data_values.push_str(tai_minus_utc);
+            let ntp: i64 = ntp.parse().map_err(|_| invalid())?;
+            entries.push(Entry {
+                since: ntp - NTP_UNIX_OFFSET,
+                tai_minus_utc: tai_minus_utc.parse().map_err(|_| invalid())?,
+            });
+        }
+        if entries.is_empty()
+            || entries
+                .windows(2)
+                .any(|w| w[0].since >= w[1].since || w[1].tai_minus_utc != w[0].tai_minus_utc + 1)
+        {
+            return Err(Error::InvalidTable);
+        }
+        if hash.is_some_and(|hash| {
+            sha1(format!("{}{}{}", updated, expiration, data_values).as_bytes()) != hash
+        }) {
+            return Err(Error::Hash);
+        }
+        Ok(Self { entries, expires })
+    }
+
+    /// Read an IERS `leap-seconds.list` file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Unix timestamp after which the list may be missing leap seconds
+    ///
+    /// The conversions don't check this, and use the last offset of the list
+    /// for every later date-time.
+    pub fn expires(&self) -> Option<i64> {
+        self.expires
+    }
+
+    fn entry_at(&self, unix_timestamp: i64) -> Result<&Entry, Error> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.since <= unix_timestamp)
+            .ok_or(Error::BeforeTable)
+    }
+
+    /// Unix timestamp of the date-time, treating second 60 as second 59, and whether it was second 60
+    fn unix_timestamp<Y: YearDigits>(date_time: &PreciseShiftedDateTime<Y>) -> (i64, bool) {
+        let timestamp = date_time.unix_timestamp();
+        if u8::from(date_time.second) == 60 {
+            (timestamp - 1, true)
+        } else {
+            (timestamp, false)
+        }
+    }
+
+    /// The difference TAI - UTC in seconds at a date-time
+    ///
+    /// After the list [expires](Self::expires) this is the last offset in it.
+    pub fn tai_minus_utc<Y: YearDigits>(
+        &self,
+        date_time: &PreciseShiftedDateTime<Y>,
+    ) -> Result<i32, Error> {
+        let (timestamp, _) = Self::unix_timestamp(date_time);
+        Ok(self.entry_at(timestamp)?.tai_minus_utc)
+    }
+
+    /// Check that a date-time at second 60 is at a scheduled leap second
+    ///
+    /// Date-times at other seconds are always accepted.
+    pub fn check_leap_second<Y: YearDigits>(
+        &self,
+        date_time: &PreciseShiftedDateTime<Y>,
+    ) -> Result<(), Error> {
+        let (timestamp, leap) = Self::unix_timestamp(date_time);
+        if !leap {
+            return Ok(());
+        }
+        let before = self.entry_at(timestamp)?;
+        match self
+            .entries
+            .iter()
+            .find(|entry| entry.since == timestamp + 1)
+        {
+            Some(after) if after.tai_minus_utc - before.tai_minus_utc == 1 => Ok(()),
+            _ => Err(Error::NotALeapSecond),
+        }
+    }
+
+    /// The point in TAI of a date-time, extrapolated with the last offset
+    /// after the list [expires](Self::expires)
+    pub fn to_tai<Y: YearDigits>(
+        &self,
+        date_time: &PreciseShiftedDateTime<Y>,
+    ) -> Result<TaiTime, Error> {
+        self.check_leap_second(date_time)?;
+        let (timestamp, leap) = Self::unix_timestamp(date_time);
+        let entry = self.entry_at(timestamp)?;
+        Ok(TaiTime {
+            seconds: timestamp + entry.tai_minus_utc as i64 + leap as i64,
+            nanosecond: date_time.nanosecond,
+        })
+    }
+
+    /// The UTC date-time of a point in TAI
+    ///
+    /// Like [`to_tai`](Self::to_tai), this uses the last offset of the list
+    /// after it expires.
+    pub fn from_tai<Y: YearDigits>(
+        &self,
+        tai: TaiTime,
+    ) -> Result<PreciseShiftedDateTime<Y>, Error> {
+        let idx = self
+            .entries
+            .iter()
+            .rposition(|entry| tai.seconds >= entry.since + entry.tai_minus_utc as i64)
+            .ok_or(Error::BeforeTable)?;
+        let timestamp = tai.seconds - self.entries[idx].tai_minus_utc as i64;
+        match self.entries.get(idx + 1) {
+            // Only during an inserted leap second does TAI reach the next
+            // entry's start before its offset applies.
+            Some(next) if timestamp >= next.since => {
+                let mut date_time =
+                    PreciseShiftedDateTime::from_unix_timestamp(timestamp - 1, tai.nanosecond)?;
+                date_time.second = Second::new(60)?;
+                Ok(date_time)
+            }
+            _ => Ok(PreciseShiftedDateTime::from_unix_timestamp(
+                timestamp,
+                tai.nanosecond,
+            )?),
+        }
+    }
+
+    pub fn to_gps<Y: YearDigits>(
+        &self,
+        date_time: &PreciseShiftedDateTime<Y>,
+    ) -> Result<GpsTime, Error> {
+        let tai = self.to_tai(date_time)?;
+        Ok(GpsTime {
+            seconds: tai.seconds - TAI_MINUS_GPS - GPS_EPOCH,
+            nanosecond: tai.nanosecond,
+        })
+    }
+
+    /// The UTC date-time of a point in GPS time
+    pub fn from_gps<Y: YearDigits>(
+        &self,
+        gps: GpsTime,
+    ) -> Result<PreciseShiftedDateTime<Y>, Error> {
+        self.from_tai(TaiTime {
+            seconds: gps.seconds + TAI_MINUS_GPS + GPS_EPOCH,
+            nanosecond: gps.nanosecond,
+        })
+    }
+}
+
+/// SHA-1 of `data`, as five big-endian words
+///
+/// The IERS list uses it to check its integrity, not for security.
+fn sha1(data: &[u8]) -> [u32; 5] {
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+    for block in message.chunks_exact(64) {
+        let mut words = [0u32; 80];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().expect("chunks have 4 bytes"));
+        }
+        for i in 16..80 {
+            words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in words.into_iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            (a, b, c, d, e) = (temp, a, b.rotate_left(30), c, d);
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+    state
+}
+
+impl Default for LeapSecondTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sha1, Error, GpsTime, LeapSecondTable, TaiTime};
+    use crate::{parse_rfc3339_datetime, PreciseShiftedDateTime};
+
+    #[test]
+    fn test_builtin_matches_file() {
+        let datafile = include_str!("../data/leap-seconds.list");
+        let table = LeapSecondTable::parse(datafile).unwrap();
+        assert_eq!(table, LeapSecondTable::builtin());
+        let from_file = LeapSecondTable::from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/leap-seconds.list"
+        ))
+        .unwrap();
+        assert_eq!(from_file, table);
+        for entry in table.entries {
+            let dt: PreciseShiftedDateTime =
+                PreciseShiftedDateTime::from_unix_timestamp(entry.since, 0.try_into().unwrap())
+                    .unwrap();
+            assert!(
+                format!("{}", dt).ends_with("-01T00:00:00.0Z"),
+                "{} is not at the start of a month",
+                dt
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            sha1(b"abc"),
+            [0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d]
+        );
+        assert_eq!(
+            sha1(&[b'a'; 1000]),
+            [0x291e9a6c, 0x66994949, 0xb57ba5e6, 0x50361e98, 0xfc36b1ba]
+        );
+        let datafile = include_str!("../data/leap-seconds-2026-06-28.list");
+        let table = LeapSecondTable::parse(datafile).unwrap();
+        assert_eq!(table.entries, LeapSecondTable::builtin().entries);
+        assert_eq!(table.expires(), Some(1782604800));
+        for (from, to) in [
+            ("#@\t3991593600", "#@\t4007404800"),
+            ("#$\t3960835200", "#$\t3960835201"),
+            ("3692217600  ", "3692217601  "),
+            ("#h\t49db2447", "#h\t49db2448"),
+        ] {
+            let changed = datafile.replace(from, to);
+            assert_ne!(changed, datafile);
+            assert!(
+                matches!(LeapSecondTable::parse(&changed), Err(Error::Hash)),
+                "{}",
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        for list in [
+            "",
+            "2272060800\t10\n2272060800\t11\n",
+            "2272060800\t10\n2287785600\t12\n",
+            "2272060800\t10\n2287785600\t9\n",
+        ] {
+            assert!(
+                matches!(LeapSecondTable::parse(list), Err(Error::InvalidTable)),
+                "{:?}",
+                list
+            );
+        }
+
+        let error = LeapSecondTable::from_file("/nonexistent/leap-seconds.list").unwrap_err();
+        assert!(matches!(error, Error::Io(_)));
+        assert!(std::error::Error::source(&error).is_some());
+        assert!(error
+            .to_string()
+            .starts_with("could not read leap second list"));
+    }
+
+    #[test]
+    fn test_check_leap_second() {
+        let table = LeapSecondTable::builtin();
+        let dt = parse_rfc3339_datetime("2016-12-31T23:59:60Z").unwrap();
+        assert!(table.check_leap_second(&dt).is_ok());
+        let dt = parse_rfc3339_datetime("1990-12-31T15:59:60-08:00").unwrap();
+        assert!(table.check_leap_second(&dt).is_ok());
+        let dt = parse_rfc3339_datetime("2017-12-31T23:59:60Z").unwrap();
+        assert!(matches!(
+            table.check_leap_second(&dt),
+            Err(Error::NotALeapSecond)
+        ));
+    }
+
+    #[test]
+    fn test_tai_around_leap_second() {
+        let table = LeapSecondTable::builtin();
+        let times = [
+            "2016-12-31T23:59:59Z",
+            "2016-12-31T23:59:60Z",
+            "2017-01-01T00:00:00Z",
+        ];
+        let mut previous = None;
+        for time in times {
+            let dt = parse_rfc3339_datetime(time).unwrap();
+            let tai = table.to_tai(&dt).unwrap();
+            if let Some(previous) = previous {
+                assert_eq!(tai.seconds, previous + 1);
+            }
+            previous = Some(tai.seconds);
+            let back: PreciseShiftedDateTime = table.from_tai(tai).unwrap();
+            assert_eq!(back, dt);
+        }
+        let dt = parse_rfc3339_datetime("2017-01-01T00:00:00Z").unwrap();
+        assert_eq!(table.to_tai(&dt).unwrap().seconds, dt.unix_timestamp() + 37);
+    }
+
+    #[test]
+    fn test_gps() {
+        let table = LeapSecondTable::builtin();
+        let dt = parse_rfc3339_datetime("1980-01-06T00:00:00Z").unwrap();
+        assert_eq!(
+            table.to_gps(&dt).unwrap(),
+            GpsTime {
+                seconds: 0,
+                nanosecond: 0.try_into().unwrap()
+            }
+        );
+        let dt = parse_rfc3339_datetime("2017-01-01T00:00:00.5Z").unwrap();
+        let gps = table.to_gps(&dt).unwrap();
+        assert_eq!(gps.seconds, dt.unix_timestamp() - 315_964_800 + 18);
+        let back: PreciseShiftedDateTime = table.from_gps(gps).unwrap();
+        assert_eq!(back, dt);
+    }
+
+    #[test]
+    fn test_before_table() {
+        let table = LeapSecondTable::builtin();
+        let dt = parse_rfc3339_datetime("1971-12-31T23:59:59Z").unwrap();
+        assert!(matches!(table.to_tai(&dt), Err(Error::BeforeTable)));
+        let tai = TaiTime {
+            seconds: 0,
+            nanosecond: 0.try_into().unwrap(),
+        };
+        assert!(matches!(
+            table.from_tai::<crate::components::SimpleYear>(tai),
+            Err(Error::BeforeTable)
+        ));
+    }
+}
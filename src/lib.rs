@@ -1,24 +1,51 @@
 #![doc = include_str!("../README.md")]
 
+mod calendar;
 mod combined;
 mod components;
+mod fast;
 mod parse;
 
 mod parse_utils;
 
+use core::fmt;
+
+pub use components::Error as ComponentError;
 use components::SimpleYear;
 pub use components::{
-    Day, Hour, Minute, Month, Nanosecond, Second, Year, ExtendedYear,
+    Day, ExtendedYear, Hour, Minute, Month, Nanosecond, Second, Timeshift, Week, Weekday, Year,
 };
 
 pub use combined::{
-    LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime, PreciseLocalTime,
-    PreciseShiftedDateTime, ShiftedDateTime,
+    AnyDateTime, LocalDate, LocalDateTime, LocalTime, MonthOverflow, PreciseLocalDateTime,
+    PreciseLocalTime, PreciseShiftedDateTime, ShiftedDateTime,
 };
 
 pub use parse::Builder;
 
+pub mod asn1;
 pub mod duration;
+pub mod format;
+pub mod http_date;
+pub mod iso8601;
+pub mod ixdtf;
+pub mod leap_seconds;
+pub mod posix_tz;
+pub mod rfc5322;
+pub mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(
+    feature = "postgres",
+    feature = "rusqlite",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-mysql"
+))]
+mod sql;
+pub mod stream;
+pub mod tz;
+pub mod xsd;
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -32,6 +59,17 @@ pub enum ErrorKind<'a, Y = SimpleYear> {
     BuildError(parse::BuildError<Y>),
 }
 
+impl<'a, Y: fmt::Debug> fmt::Display for Error<'a, Y> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::ParseError(e) => write!(f, "parse error: {}", e),
+            ErrorKind::BuildError(e) => write!(f, "invalid date or time: {}", e),
+        }
+    }
+}
+
+impl<'a, Y: fmt::Debug> std::error::Error for Error<'a, Y> {}
+
 impl<'a, Y> From<parse::BuildError<Y>> for Error<'a, Y> {
     fn from(value: parse::BuildError<Y>) -> Self {
         Error {
@@ -55,6 +93,9 @@ impl<'a> From<parse_utils::ParseError<'a>> for Error<'a> {
 /// the fractional seconds part is optional and the T separator can be replaced
 /// with a space.
 ///
+/// The common `YYYY-MM-DDThh:mm:ss[.f]Z` layout is decoded by a faster path
+/// that gives the same results.
+///
 /// ## Example
 /// ```rust
 /// # use datetimeparse::parse_rfc3339_datetime;
@@ -69,11 +110,52 @@ impl<'a> From<parse_utils::ParseError<'a>> for Error<'a> {
 /// assert_eq!(dt.nanosecond, Nanosecond::new(763072000).unwrap());
 /// ```
 pub fn parse_rfc3339_datetime(inp: &str) -> Result<PreciseShiftedDateTime, Error<'_>> {
+    if let Some(date_time) = fast::parse_rfc3339_utc(inp.as_bytes()) {
+        return Ok(date_time);
+    }
     let mut parser = parse::ParseContext::new_rfc3339().into_parser();
     parser.parse_precise_shifted_date_time(inp.as_bytes())?;
     Ok(parser.build_precise_shifted_date_time()?)
 }
 
+/// Parse a RFC3339 formatted datetime string where the offset is optional.
+///
+/// The returned [`AnyDateTime`] tells whether the input had fractional
+/// seconds and an offset, so mixed input is parsed in a single pass. Use
+/// [`Builder::default_timeshift`] to treat a missing offset as a known one.
+///
+/// ## Example
+/// ```rust
+/// # use datetimeparse::{parse_rfc3339_any_datetime, AnyDateTime};
+/// let dt = parse_rfc3339_any_datetime("2023-09-17T09:08:58").unwrap();
+/// assert!(matches!(dt, AnyDateTime::Local(_)));
+/// let dt = parse_rfc3339_any_datetime("2023-09-17T09:08:58.763072Z").unwrap();
+/// assert!(matches!(dt, AnyDateTime::PreciseShifted(_)));
+/// ```
+pub fn parse_rfc3339_any_datetime(inp: &str) -> Result<AnyDateTime, Error<'_>> {
+    let mut parser = parse::ParseContext::new_rfc3339().into_parser();
+    parser.parse_any_date_time(inp.as_bytes())?;
+    Ok(parser.build_any_date_time()?)
+}
+
+/// Parse any ISO 8601 date, time, date-time, duration or interval.
+///
+/// The returned [`iso8601::Iso8601`] tells which representation was found.
+/// Ambiguous inputs are rejected, see the [`iso8601`] module.
+///
+/// ## Example
+/// ```rust
+/// # use datetimeparse::parse_iso8601_any;
+/// # use datetimeparse::iso8601::Iso8601;
+/// assert!(matches!(parse_iso8601_any("2023-260"), Ok(Iso8601::OrdinalDate(_))));
+/// assert!(matches!(parse_iso8601_any("2023-09"), Ok(Iso8601::YearMonth { .. })));
+/// assert!(matches!(parse_iso8601_any("2023-W37-7T09:08Z"), Ok(Iso8601::DateTime(_))));
+/// assert!(matches!(parse_iso8601_any("09:08:58Z"), Ok(Iso8601::ShiftedTime { .. })));
+/// ```
+pub fn parse_iso8601_any(inp: &str) -> Result<iso8601::Iso8601, Error<'_>> {
+    iso8601::parse_any(inp.as_bytes())
+}
+
 /// Parse a RFC3339 formatted date string.
 ///
 /// ## Example
@@ -122,6 +204,15 @@ mod test_parse_rfc3339_datetime {
         }
     }
 
+    #[test]
+    fn reject_all_invalid_datetime_from_file() {
+        let datafile = include_str!("../data/datetime-invalid-values-rfc.txt");
+        for line in datafile.lines() {
+            let dt = parse_rfc3339_datetime(line);
+            assert!(dt.is_err(), "{}: {:?}", line, dt);
+        }
+    }
+
     #[test]
     fn parse_all_time_from_file() {
         let datafile = include_str!("../data/time-test-values-rfc.txt");
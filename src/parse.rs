@@ -1,17 +1,18 @@
-use std::{collections::VecDeque, str};
+use std::{collections::VecDeque, fmt, str};
 
 use crate::{
     combined::{
-        LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime, PreciseLocalTime,
-        PreciseShiftedDateTime, ShiftedDateTime,
+        check_time, AnyDateTime, LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime,
+        PreciseLocalTime, PreciseShiftedDateTime, ShiftedDateTime,
     },
     components::{
-        Day, ExtendedYear, Hour, Minute, Month, Nanosecond, Second, SimpleYear, Timeshift, Year,
-        YearDigits,
+        Day, Error, ExtendedYear, Hour, Minute, Month, Nanosecond, Second, SimpleYear, Timeshift,
+        Year, YearDigits,
     },
     parse_utils::{any_of, is_digit, parse_n_digits, tag, take_while, ParseError},
 };
 
+#[derive(Clone)]
 pub struct Builder {
     context: ParseContext,
 }
@@ -44,6 +45,25 @@ impl Builder {
         self.context.empty_time_separator = allowed;
         self
     }
+    /// Build date-times at `24:00:00` as `00:00:00` of the next day
+    pub fn normalize_end_of_day(&mut self, normalize: bool) -> &mut Self {
+        self.context.normalize_end_of_day = normalize;
+        self
+    }
+    /// Require a `+` or `-` before extended years, as ISO 8601 does for
+    /// expanded years and as they are displayed
+    ///
+    /// Without it an extended year is only its digits, which can't be negative.
+    pub fn year_sign_required(&mut self, required: bool) -> &mut Self {
+        self.context.year_sign = required;
+        self
+    }
+    /// Use `timeshift` for date-times without an offset, instead of failing
+    /// or building a local date-time
+    pub fn default_timeshift(&mut self, timeshift: Option<Timeshift>) -> &mut Self {
+        self.context.default_timeshift = timeshift;
+        self
+    }
     pub fn into_parser(self) -> Parser<SimpleYear> {
         self.context.into_parser()
     }
@@ -94,14 +114,47 @@ pub enum BuildError<Y> {
         got: Element<Y>,
         expected: ElementTag,
     },
+    /// The elements are valid on their own, but not in this combination
+    Invalid(Error),
+}
+
+impl<Y: fmt::Debug> fmt::Display for BuildError<Y> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::NotEnoughElements => write!(f, "not enough elements"),
+            BuildError::Unexpected { got, expected } => {
+                write!(f, "expected {:?}, got {:?}", expected, got)
+            }
+            BuildError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<Y: fmt::Debug> std::error::Error for BuildError<Y> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
+impl<Y> From<Error> for BuildError<Y> {
+    fn from(value: Error) -> Self {
+        BuildError::Invalid(value)
+    }
+}
+
+#[derive(Clone)]
 pub struct ParseContext {
     space_as_date_time_separator: bool,
     empty_date_separator: bool,
     empty_time_separator: bool,
     negative_zero: bool,
     lower_case_t_z: bool,
+    normalize_end_of_day: bool,
+    year_sign: bool,
+    default_timeshift: Option<Timeshift>,
 }
 
 impl ParseContext {
@@ -112,6 +165,9 @@ impl ParseContext {
             empty_time_separator: false,
             negative_zero: true,
             lower_case_t_z: true,
+            normalize_end_of_day: false,
+            year_sign: false,
+            default_timeshift: None,
         }
     }
 
@@ -122,6 +178,9 @@ impl ParseContext {
             empty_time_separator: false,
             negative_zero: true,
             lower_case_t_z: false,
+            normalize_end_of_day: false,
+            year_sign: false,
+            default_timeshift: None,
         }
     }
 
@@ -132,6 +191,9 @@ impl ParseContext {
             empty_time_separator: true,
             negative_zero: false,
             lower_case_t_z: false,
+            normalize_end_of_day: false,
+            year_sign: false,
+            default_timeshift: None,
         }
     }
 
@@ -142,6 +204,14 @@ impl ParseContext {
         }
     }
 
+    /// This profile reading extended years with their sign, as they are displayed
+    pub(crate) fn with_year_sign(self) -> Self {
+        Self {
+            year_sign: true,
+            ..self
+        }
+    }
+
     fn allows_empty_date_separators(&self) -> bool {
         self.empty_date_separator
     }
@@ -210,10 +280,20 @@ where
     Y: YearDigits,
 {
     pub fn parse_year<'a>(&mut self, data: &'a [u8]) -> Result<&'a [u8], ParseError<'a>> {
+        let (sign, data) = if Y::signed() && self.context.year_sign {
+            match data.first() {
+                Some(b'+') => (1, &data[1..]),
+                Some(b'-') => (-1, &data[1..]),
+                Some(_) => return Err(ParseError::Fail(data)),
+                None => return Err(ParseError::UnexpectedEof { needed: 1 }),
+            }
+        } else {
+            (1, data)
+        };
         let (year, rest) = parse_n_digits(Y::digits(), data)?;
-        let year = year.try_into().map_err(|_| ParseError::RangeError)?;
+        let year: i32 = year.try_into().map_err(|_| ParseError::RangeError)?;
         self.elements
-            .push_back(Element::Year(Y::from_digits(year)?));
+            .push_back(Element::Year(Y::from_digits(sign * year)?));
         Ok(rest)
     }
 
@@ -376,6 +456,22 @@ where
         Ok(rest)
     }
 
+    /// Parse an offset when the input starts with one, or push the default
+    pub(crate) fn parse_optional_timezone_offset<'a>(
+        &mut self,
+        data: &'a [u8],
+    ) -> Result<&'a [u8], ParseError<'a>> {
+        match data.first() {
+            Some(b'Z' | b'z' | b'+' | b'-') => self.parse_timezone_offset(data),
+            _ => {
+                if let Some(timeshift) = self.context.default_timeshift {
+                    self.elements.push_back(Element::Timeshift(timeshift));
+                }
+                Ok(data)
+            }
+        }
+    }
+
     pub fn parse_local_date_time<'a>(
         &mut self,
         data: &'a [u8],
@@ -393,7 +489,7 @@ where
         let rest = self.parse_local_date_time(data)?;
         let rest = match self.parse_fractional_separator(rest) {
             Ok(rest) => self.parse_fractional_seconds(rest)?,
-            Err(ParseError::Fail(_)) => {
+            Err(ParseError::Fail(_) | ParseError::UnexpectedEof { .. }) => {
                 self.elements
                     .push_back(Element::Nanosecond(Nanosecond::new(0)?));
                 return Ok(rest);
@@ -408,7 +504,10 @@ where
         data: &'a [u8],
     ) -> Result<&'a [u8], ParseError<'a>> {
         let rest = self.parse_local_date_time(data)?;
-        let rest = self.parse_timezone_offset(rest)?;
+        let rest = match self.context.default_timeshift {
+            Some(_) => self.parse_optional_timezone_offset(rest)?,
+            None => self.parse_timezone_offset(rest)?,
+        };
         Ok(rest)
     }
 
@@ -417,10 +516,25 @@ where
         data: &'a [u8],
     ) -> Result<&'a [u8], ParseError<'a>> {
         let rest = self.parse_precise_local_date_time(data)?;
-        let rest = self.parse_timezone_offset(rest)?;
+        let rest = match self.context.default_timeshift {
+            Some(_) => self.parse_optional_timezone_offset(rest)?,
+            None => self.parse_timezone_offset(rest)?,
+        };
         Ok(rest)
     }
 
+    /// Parse a date-time with optional fractional seconds and an optional
+    /// offset, to be built with [`Parser::build_any_date_time`]
+    pub fn parse_any_date_time<'a>(&mut self, data: &'a [u8]) -> Result<&'a [u8], ParseError<'a>> {
+        let rest = self.parse_local_date_time(data)?;
+        let rest = match self.parse_fractional_separator(rest) {
+            Ok(rest) => self.parse_fractional_seconds(rest)?,
+            Err(ParseError::Fail(_) | ParseError::UnexpectedEof { .. }) => rest,
+            Err(e) => return Err(e),
+        };
+        self.parse_optional_timezone_offset(rest)
+    }
+
     pub fn parse_precise_local_time<'a>(
         &mut self,
         data: &'a [u8],
@@ -428,7 +542,7 @@ where
         let rest = self.parse_time(data)?;
         let rest = match self.parse_fractional_separator(rest) {
             Ok(rest) => self.parse_fractional_seconds(rest)?,
-            Err(ParseError::Fail(_)) => {
+            Err(ParseError::Fail(_) | ParseError::UnexpectedEof { .. }) => {
                 self.elements
                     .push_back(Element::Nanosecond(Nanosecond::new(0)?));
                 return Ok(rest);
@@ -438,6 +552,16 @@ where
         Ok(rest)
     }
 
+    /// Add an element that was parsed or defaulted outside of the `parse_*` methods
+    pub(crate) fn push_element(&mut self, element: Element<Y>) {
+        self.elements.push_back(element);
+    }
+
+    /// Forget the parsed elements, keeping their storage for the next input
+    pub(crate) fn reset(&mut self) {
+        self.elements.clear();
+    }
+
     pub fn build_date(mut self) -> Result<LocalDate<Y>, BuildError<Y>> {
         let year = match self.elements.pop_front() {
             Some(Element::Year(year)) => year,
@@ -504,6 +628,7 @@ where
             None => return Err(BuildError::NotEnoughElements),
         };
 
+        check_time(hour, minute, second, Nanosecond::new(0)?, None)?;
         Ok(LocalTime {
             hour,
             minute,
@@ -511,9 +636,7 @@ where
         })
     }
 
-    pub fn build_precise_local_time(
-        mut self,
-    ) -> Result<PreciseLocalTime, BuildError<Y>> {
+    pub fn build_precise_local_time(mut self) -> Result<PreciseLocalTime, BuildError<Y>> {
         let hour = match self.elements.pop_front() {
             Some(Element::Hour(hour)) => hour,
             Some(e) => {
@@ -555,6 +678,7 @@ where
             None => return Err(BuildError::NotEnoughElements),
         };
 
+        check_time(hour, minute, second, nanosecond, None)?;
         Ok(PreciseLocalTime {
             hour,
             minute,
@@ -563,9 +687,27 @@ where
         })
     }
 
-    pub fn build_local_date_time(
-        mut self,
-    ) -> Result<LocalDateTime<Y>, BuildError<Y>> {
+    /// Like [`Self::build_precise_local_time`], with the offset that follows
+    /// the time if one was parsed
+    pub(crate) fn build_precise_time_with_offset(
+        self,
+    ) -> Result<(PreciseLocalTime, Option<Timeshift>), BuildError<Y>> {
+        let timeshift = match self.elements.back() {
+            Some(Element::Timeshift(timeshift)) => Some(*timeshift),
+            _ => None,
+        };
+        let time = self.build_precise_local_time()?;
+        check_time(
+            time.hour,
+            time.minute,
+            time.second,
+            time.nanosecond,
+            timeshift,
+        )?;
+        Ok((time, timeshift))
+    }
+
+    pub fn build_local_date_time(mut self) -> Result<LocalDateTime<Y>, BuildError<Y>> {
         let year = match self.elements.pop_front() {
             Some(Element::Year(year)) => year,
             Some(e) => {
@@ -626,19 +768,19 @@ where
             }
             None => return Err(BuildError::NotEnoughElements),
         };
-        Ok(LocalDateTime {
+        check_time(hour, minute, second, Nanosecond::new(0)?, None)?;
+        let date_time = LocalDateTime {
             year,
             month,
             day,
             hour,
             minute,
             second,
-        })
+        };
+        self.finish_date_time(date_time, LocalDateTime::normalize_end_of_day)
     }
 
-    pub fn build_shifted_date_time(
-        mut self,
-    ) -> Result<ShiftedDateTime<Y>, BuildError<Y>> {
+    pub fn build_shifted_date_time(mut self) -> Result<ShiftedDateTime<Y>, BuildError<Y>> {
         let year = match self.elements.pop_front() {
             Some(Element::Year(year)) => year,
             Some(e) => {
@@ -709,7 +851,8 @@ where
             }
             None => return Err(BuildError::NotEnoughElements),
         };
-        Ok(ShiftedDateTime {
+        check_time(hour, minute, second, Nanosecond::new(0)?, Some(timeshift))?;
+        let date_time = ShiftedDateTime {
             year,
             month,
             day,
@@ -717,7 +860,8 @@ where
             minute,
             second,
             timeshift,
-        })
+        };
+        self.finish_date_time(date_time, ShiftedDateTime::normalize_end_of_day)
     }
 
     pub fn build_precise_local_date_time(
@@ -793,7 +937,8 @@ where
             }
             None => return Err(BuildError::NotEnoughElements),
         };
-        Ok(PreciseLocalDateTime {
+        check_time(hour, minute, second, nanosecond, None)?;
+        let date_time = PreciseLocalDateTime {
             year,
             month,
             day,
@@ -801,11 +946,20 @@ where
             minute,
             second,
             nanosecond,
-        })
+        };
+        self.finish_date_time(date_time, PreciseLocalDateTime::normalize_end_of_day)
     }
 
     pub fn build_precise_shifted_date_time(
         mut self,
+    ) -> Result<PreciseShiftedDateTime<Y>, BuildError<Y>> {
+        self.take_precise_shifted_date_time()
+    }
+
+    /// Like [`Self::build_precise_shifted_date_time`], but keeps the parser
+    /// so it can be [reset](Self::reset) for the next input
+    pub(crate) fn take_precise_shifted_date_time(
+        &mut self,
     ) -> Result<PreciseShiftedDateTime<Y>, BuildError<Y>> {
         let year = match self.elements.pop_front() {
             Some(Element::Year(year)) => year,
@@ -887,7 +1041,8 @@ where
             }
             None => return Err(BuildError::NotEnoughElements),
         };
-        Ok(PreciseShiftedDateTime {
+        check_time(hour, minute, second, nanosecond, Some(timeshift))?;
+        let date_time = PreciseShiftedDateTime {
             year,
             month,
             day,
@@ -896,13 +1051,148 @@ where
             second,
             nanosecond,
             timeshift,
+        };
+        self.finish_date_time(date_time, PreciseShiftedDateTime::normalize_end_of_day)
+    }
+
+    /// Build the date-time type that matches the parsed elements
+    pub fn build_any_date_time(self) -> Result<AnyDateTime<Y>, BuildError<Y>> {
+        let precise = self
+            .elements
+            .iter()
+            .any(|e| matches!(e, Element::Nanosecond(_)));
+        let shifted = self
+            .elements
+            .iter()
+            .any(|e| matches!(e, Element::Timeshift(_)));
+        Ok(match (precise, shifted) {
+            (false, false) => AnyDateTime::Local(self.build_local_date_time()?),
+            (true, false) => AnyDateTime::PreciseLocal(self.build_precise_local_date_time()?),
+            (false, true) => AnyDateTime::Shifted(self.build_shifted_date_time()?),
+            (true, true) => AnyDateTime::PreciseShifted(self.build_precise_shifted_date_time()?),
         })
     }
+
+    fn finish_date_time<T>(
+        &self,
+        date_time: T,
+        normalize: impl FnOnce(T) -> Result<T, Error>,
+    ) -> Result<T, BuildError<Y>> {
+        if self.context.normalize_end_of_day {
+            Ok(normalize(date_time)?)
+        } else {
+            Ok(date_time)
+        }
+    }
+}
+
+/// One of the `Parser::parse_*` methods
+pub(crate) type ParseFn<Y> =
+    for<'a> fn(&mut Parser<Y>, &'a [u8]) -> Result<&'a [u8], ParseError<'a>>;
+
+/// Parse the start of `s`, returning the input that is left over
+pub(crate) fn parse_prefix<Y, T>(
+    context: ParseContext,
+    s: &str,
+    parse: ParseFn<Y>,
+    build: fn(Parser<Y>) -> Result<T, BuildError<Y>>,
+) -> Result<(T, &str), Error> {
+    let mut parser = context.into_parser();
+    let rest = parse(&mut parser, s.as_bytes()).map_err(|e| match e {
+        ParseError::RangeError => Error::Range,
+        _ => Error::Parse,
+    })?;
+    let rest = s.get(s.len() - rest.len()..).ok_or(Error::Parse)?;
+    let value = build(parser).map_err(|e| match e {
+        BuildError::Invalid(e) => e,
+        _ => Error::Parse,
+    })?;
+    Ok((value, rest))
+}
+
+/// Parse all of `s`, failing when there is input left over
+pub(crate) fn parse_complete<Y, T>(
+    context: ParseContext,
+    s: &str,
+    parse: ParseFn<Y>,
+    build: fn(Parser<Y>) -> Result<T, BuildError<Y>>,
+) -> Result<T, Error> {
+    match parse_prefix(context, s, parse, build)? {
+        (value, "") => Ok(value),
+        _ => Err(Error::Parse),
+    }
+}
+
+macro_rules! impl_from_str {
+    ($type:ident<Y>, $parse:ident, $build:ident) => {
+        /// Parses the RFC 3339 format written by [`fmt::Display`](core::fmt::Display)
+        impl<Y: YearDigits> str::FromStr for $type<Y> {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_complete(
+                    ParseContext::new_rfc3339().with_year_sign(),
+                    s,
+                    Parser::<Y>::$parse,
+                    Parser::<Y>::$build,
+                )
+            }
+        }
+    };
+    ($type:ident, $parse:ident, $build:ident) => {
+        /// Parses the RFC 3339 format written by [`fmt::Display`](core::fmt::Display)
+        impl str::FromStr for $type {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_complete(
+                    ParseContext::new_rfc3339().with_year_sign(),
+                    s,
+                    Parser::<SimpleYear>::$parse,
+                    Parser::<SimpleYear>::$build,
+                )
+            }
+        }
+    };
 }
 
+impl_from_str!(LocalDate<Y>, parse_date, build_date);
+impl_from_str!(LocalTime, parse_time, build_time);
+impl_from_str!(
+    PreciseLocalTime,
+    parse_precise_local_time,
+    build_precise_local_time
+);
+impl_from_str!(
+    LocalDateTime<Y>,
+    parse_local_date_time,
+    build_local_date_time
+);
+impl_from_str!(
+    PreciseLocalDateTime<Y>,
+    parse_precise_local_date_time,
+    build_precise_local_date_time
+);
+impl_from_str!(
+    ShiftedDateTime<Y>,
+    parse_shifted_date_time,
+    build_shifted_date_time
+);
+impl_from_str!(
+    PreciseShiftedDateTime<Y>,
+    parse_precise_shifted_date_time,
+    build_precise_shifted_date_time
+);
+impl_from_str!(AnyDateTime<Y>, parse_any_date_time, build_any_date_time);
+
 #[cfg(test)]
 mod tests {
-    use super::Parser;
+    use super::{Builder, Parser};
+    use crate::{
+        combined::AnyDateTime,
+        components::{Error, ExtendedYear, Hour, Minute, Timeshift},
+        LocalDate, LocalTime, PreciseLocalTime, PreciseShiftedDateTime,
+    };
 
     #[test]
     pub fn test_parse_time() {
@@ -913,4 +1203,145 @@ mod tests {
         let time = parser.build_time().unwrap();
         assert_eq!(time, (20, 10, 21).try_into().unwrap())
     }
+
+    #[test]
+    pub fn test_parse_precise_without_fraction() {
+        // A fraction is optional, also when the input ends right after the seconds
+        let mut parser = Parser::new();
+        assert_eq!(parser.parse_precise_local_time(b"20:10:21").unwrap(), b"");
+        let time = parser.build_precise_local_time().unwrap();
+        assert_eq!(format!("{}", time), "20:10:21.0");
+        let mut parser = Parser::new();
+        assert_eq!(
+            parser
+                .parse_precise_local_date_time(b"2023-09-17T20:10:21")
+                .unwrap(),
+            b""
+        );
+        let date_time = parser.build_precise_local_date_time().unwrap();
+        assert_eq!(format!("{}", date_time), "2023-09-17T20:10:21.0");
+        let mut parser = Parser::new();
+        assert_eq!(parser.parse_precise_local_time(b"20:10:21Z").unwrap(), b"Z");
+        // A separator still needs digits after it
+        assert!(Parser::new()
+            .parse_precise_local_time(b"20:10:21.")
+            .is_err());
+    }
+
+    #[test]
+    pub fn test_normalize_end_of_day() {
+        let mut builder = Builder::new_rfc3339();
+        builder.normalize_end_of_day(true);
+        let mut parser = builder.into_parser();
+        parser
+            .parse_precise_shifted_date_time(b"2023-02-28T24:00:00Z")
+            .unwrap();
+        let dt = parser.build_precise_shifted_date_time().unwrap();
+        assert_eq!(format!("{}", dt), "2023-03-01T00:00:00.0Z");
+    }
+
+    #[test]
+    pub fn test_from_str() {
+        let dt: PreciseShiftedDateTime = "2023-09-17T09:08:58.5+02:00".parse().unwrap();
+        assert_eq!(format!("{}", dt), "2023-09-17T09:08:58.5+02:00");
+        let time: LocalTime = "09:08:58".parse().unwrap();
+        assert_eq!(format!("{}", time), "09:08:58");
+        let time: PreciseLocalTime = "09:08:58".parse().unwrap();
+        assert_eq!(format!("{}", time), "09:08:58.0");
+        let date: LocalDate<ExtendedYear<6>> = "-000044-03-15".parse().unwrap();
+        assert_eq!(format!("{}", date), "-000044-03-15");
+        assert!(matches!(
+            "2023-09-17T09:08:58Z trailing".parse::<PreciseShiftedDateTime>(),
+            Err(Error::Parse)
+        ));
+        assert!(matches!(
+            "2023-09-17T24:00:01Z".parse::<PreciseShiftedDateTime>(),
+            Err(Error::EndOfDay)
+        ));
+        assert!("2023-09-17".parse::<LocalDate<ExtendedYear<6>>>().is_err());
+        assert!("002023-09-17"
+            .parse::<LocalDate<ExtendedYear<6>>>()
+            .is_err());
+    }
+
+    #[test]
+    pub fn test_extended_year_sign() {
+        let parse = |builder: &Builder, s: &'static str| {
+            let mut parser = builder.clone().into_extended_year_parser::<6>();
+            parser
+                .parse_date(s.as_bytes())
+                .map_err(|_| ())
+                .and_then(|rest| {
+                    assert!(rest.is_empty());
+                    parser.build_date().map_err(|_| ())
+                })
+                .map(|date| date.to_string())
+        };
+        let mut builder = Builder::new_rfc3339();
+        assert_eq!(parse(&builder, "002023-09-17").unwrap(), "+002023-09-17");
+        assert!(parse(&builder, "+002023-09-17").is_err());
+        builder.year_sign_required(true);
+        assert!(parse(&builder, "002023-09-17").is_err());
+        assert_eq!(parse(&builder, "+002023-09-17").unwrap(), "+002023-09-17");
+        assert_eq!(parse(&builder, "-000044-03-15").unwrap(), "-000044-03-15");
+        // Simple years never have a sign
+        let mut parser = builder.into_parser();
+        assert!(parser.parse_date(b"+2023-09-17").is_err());
+    }
+
+    #[test]
+    pub fn test_any_date_time() {
+        let parse = |s: &str| s.parse::<AnyDateTime>().unwrap();
+        assert!(matches!(
+            parse("2023-09-17T09:08:58"),
+            AnyDateTime::Local(_)
+        ));
+        assert!(matches!(
+            parse("2023-09-17T09:08:58.5"),
+            AnyDateTime::PreciseLocal(_)
+        ));
+        assert!(matches!(
+            parse("2023-09-17T09:08:58Z"),
+            AnyDateTime::Shifted(_)
+        ));
+        let dt = parse("2023-09-17T09:08:58.5-02:00");
+        assert!(matches!(dt, AnyDateTime::PreciseShifted(_)));
+        assert_eq!(format!("{}", dt), "2023-09-17T09:08:58.5-02:00");
+        assert!("2023-09-17T09:08:58+02".parse::<AnyDateTime>().is_err());
+        assert!("2023-09-17T09:08:58.".parse::<AnyDateTime>().is_err());
+        assert!("2023-09-17T09:08:58 ".parse::<AnyDateTime>().is_err());
+    }
+
+    #[test]
+    pub fn test_default_timeshift() {
+        let timeshift = Timeshift::offset(true, Hour::new(2).unwrap(), Minute::new(0).unwrap());
+        let mut builder = Builder::new_rfc3339();
+        builder.default_timeshift(Some(timeshift));
+        let mut parser = builder.into_parser();
+        parser
+            .parse_precise_shifted_date_time(b"2023-09-17T09:08:58")
+            .unwrap();
+        let dt = parser.build_precise_shifted_date_time().unwrap();
+        assert_eq!(format!("{}", dt), "2023-09-17T09:08:58.0+02:00");
+
+        let mut builder = Builder::new_rfc3339();
+        builder.default_timeshift(Some(timeshift));
+        let mut parser = builder.into_parser();
+        parser.parse_any_date_time(b"2023-09-17T09:08:58Z").unwrap();
+        let dt = parser.build_any_date_time().unwrap();
+        assert_eq!(dt.timeshift(), Some(Timeshift::utc()));
+
+        let mut builder = Builder::new_rfc3339();
+        builder.default_timeshift(Some(timeshift));
+        let mut parser = builder.into_parser();
+        parser.parse_any_date_time(b"2023-09-17T09:08:58").unwrap();
+        let dt = parser.build_any_date_time().unwrap();
+        assert!(matches!(dt, AnyDateTime::Shifted(_)));
+        assert_eq!(dt.timeshift(), Some(timeshift));
+
+        let mut parser = Builder::new_rfc3339().into_parser();
+        assert!(parser
+            .parse_precise_shifted_date_time(b"2023-09-17T09:08:58")
+            .is_err());
+    }
 }
@@ -20,8 +20,19 @@ pub enum ParseError<'a> {
 }
 
 impl<'a> fmt::Display for ParseError<'a> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEof { needed } => {
+                write!(f, "unexpected end of input, expected {} more bytes", needed)
+            }
+            ParseError::Utf8Error => write!(f, "invalid UTF-8"),
+            ParseError::InvalidNumber => write!(f, "invalid number"),
+            ParseError::RangeError => write!(f, "value out of range"),
+            ParseError::NegativeZero => write!(f, "negative zero offset is not allowed"),
+            ParseError::Fail(rest) => {
+                write!(f, "unexpected input at `{}`", String::from_utf8_lossy(rest))
+            }
+        }
     }
 }
 
@@ -45,6 +56,13 @@ impl<'a> From<components::Error> for ParseError<'a> {
             components::Error::Range => ParseError::RangeError,
             components::Error::ParseInt(_) => ParseError::InvalidNumber,
             components::Error::Parse => ParseError::Fail(b""),
+            components::Error::EndOfDay
+            | components::Error::LeapSecond
+            | components::Error::DayOverflow
+            | components::Error::Negative
+            | components::Error::CalendarDependent
+            | components::Error::SubNanosecond
+            | components::Error::NotUtc => ParseError::RangeError,
         }
     }
 }
@@ -107,14 +125,127 @@ pub(crate) fn take_while<'a>(
     }
 }
 
-pub(crate) fn take_until<'a>(
-    cond: impl Fn(u8) -> bool,
-) -> impl Fn(&'a [u8]) -> ParseResult<&'a [u8]> {
-    take_while(move |x| !cond(x))
-}
-
 pub(crate) fn parse_n_digits(n: usize, input: &[u8]) -> ParseResult<'_, u64> {
     let (digits, rest) = take_n(n)(input)?;
     let number: u64 = str::from_utf8(digits)?.parse()?;
     Ok((number, rest))
 }
+
+/// Position of the first byte that a [`Scanner`] could not read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SyntaxError {
+    pub(crate) position: usize,
+}
+
+/// Reads a value from left to right, for the formats that report errors by
+/// byte position instead of through [`ParseError`]
+///
+/// The formats wrap it in their own scanner for the parts only they have.
+pub(crate) struct Scanner<'a> {
+    s: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(s: &'a [u8]) -> Self {
+        Scanner { s, position: 0 }
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The input that wasn't read yet
+    pub(crate) fn rest(&self) -> &'a [u8] {
+        &self.s[self.position..]
+    }
+
+    /// An error at the current position
+    pub(crate) fn error<E: From<SyntaxError>>(&self) -> E {
+        SyntaxError {
+            position: self.position,
+        }
+        .into()
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.s.get(self.position).copied()
+    }
+
+    /// Skip `n` bytes that were already looked at
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    pub(crate) fn eat(&mut self, c: u8) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    pub(crate) fn expect(&mut self, literal: &[u8]) -> Result<(), SyntaxError> {
+        if !self.rest().starts_with(literal) {
+            return Err(self.error());
+        }
+        self.position += literal.len();
+        Ok(())
+    }
+
+    /// Fails unless all of the input was read
+    pub(crate) fn end(&self) -> Result<(), SyntaxError> {
+        match self.position == self.s.len() {
+            true => Ok(()),
+            false => Err(self.error()),
+        }
+    }
+
+    pub(crate) fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a [u8] {
+        let start = self.position;
+        while self.peek().is_some_and(&f) {
+            self.position += 1;
+        }
+        &self.s[start..self.position]
+    }
+
+    /// Exactly `n` digits, which may be followed by more
+    pub(crate) fn digits(&mut self, n: usize) -> Result<&'a [u8], SyntaxError> {
+        match self.s.get(self.position..self.position + n) {
+            Some(digits) if digits.iter().all(u8::is_ascii_digit) => {
+                self.position += n;
+                Ok(digits)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Exactly `n` digits as a number
+    pub(crate) fn number(&mut self, n: usize) -> Result<u64, SyntaxError> {
+        Ok(decimal(self.digits(n)?))
+    }
+
+    /// All digits at the position as a number, which fails at the first of
+    /// them when there are fewer than `min` or more than `max`
+    pub(crate) fn number_between(&mut self, min: usize, max: usize) -> Result<u64, SyntaxError> {
+        let start = self.position;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.len() < min || digits.len() > max {
+            return Err(SyntaxError { position: start });
+        }
+        Ok(decimal(digits))
+    }
+}
+
+/// Value of ASCII digits, which must be few enough to fit
+pub(crate) fn decimal(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |value, &c| value * 10 + u64::from(c - b'0'))
+}
+
+/// Nanoseconds of the digits of a fraction of a second, of which there are
+/// at most nine
+pub(crate) fn nanoseconds(fraction: &[u8]) -> u64 {
+    decimal(fraction) * 10u64.pow(9 - fraction.len() as u32)
+}
@@ -0,0 +1,650 @@
+//! Time zones described by POSIX TZ strings
+//!
+//! A TZ string like `CET-1CEST,M3.5.0,M10.5.0/3` gives the standard time,
+//! the daylight saving time and the rules for switching between them. It is
+//! used in the `TZ` environment variable and in the footer of TZif files, see
+//! [`crate::tz`]. Offsets in a TZ string count hours west of UTC, so `CET-1`
+//! is one hour ahead of UTC.
+//!
+//! The extensions of RFC 8536 are supported: transition times can be negative
+//! and up to 167 hours.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::posix_tz::PosixTimeZone;
+//! # use datetimeparse::tz::LocalResult;
+//! # use datetimeparse::{LocalDateTime, PreciseShiftedDateTime};
+//! let zone: PosixTimeZone = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+//! let local = LocalDateTime::try_from((2023, 10, 29, 2, 30, 0)).unwrap();
+//! assert!(matches!(zone.resolve(&local), Ok(LocalResult::Fold { .. })));
+//!
+//! let utc: PreciseShiftedDateTime = "2023-09-17T09:08:58Z".parse().unwrap();
+//! assert_eq!(zone.local_time_type(utc.unix_timestamp()).abbreviation, "CEST");
+//! ```
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+    str,
+};
+
+use crate::{
+    calendar,
+    combined::{LocalDateTime, PreciseLocalDateTime, PreciseShiftedDateTime},
+    components::{self, YearDigits},
+    parse_utils::{self, SyntaxError},
+    tz::{local_seconds, resolve_local_seconds, LocalResult, LocalTimeType},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The TZ string is invalid, starting at this byte
+    Syntax { position: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Syntax { position } => write!(f, "invalid TZ string at byte {}", position),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SyntaxError> for Error {
+    fn from(value: SyntaxError) -> Self {
+        Error::Syntax {
+            position: value.position,
+        }
+    }
+}
+
+/// The day of the year on which a transition happens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// `Jn`, day 1 to 365 of the year, where February 29 is never counted
+    Julian(u16),
+    /// `n`, day 0 to 365 of the year, where February 29 is counted
+    Day(u16),
+    /// `Mm.w.d`, weekday `d` of week `w` of month `m`, where 0 is Sunday and
+    /// week 5 is the last week of the month
+    MonthWeekday { month: u8, week: u8, weekday: u8 },
+}
+
+impl Rule {
+    /// Days since 1970-01-01 of the day of this rule in a year
+    fn day(self, year: i32) -> i64 {
+        let january_first = calendar::days_from_civil(year, 1, 1);
+        match self {
+            Rule::Julian(day) => {
+                let day = i64::from(day) - 1;
+                let leap_day = calendar::is_leap_year(year) && day >= 59;
+                january_first + day + i64::from(leap_day)
+            }
+            Rule::Day(day) => january_first + i64::from(day),
+            Rule::MonthWeekday {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = calendar::days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                let end = first + i64::from(calendar::days_in_month(year, month));
+                while day >= end {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// A switch to or from daylight saving time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    pub rule: Rule,
+    /// Seconds after midnight in the local time before the switch
+    pub time: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaylightSaving {
+    pub local_time_type: LocalTimeType,
+    pub start: Transition,
+    pub end: Transition,
+}
+
+/// A time zone described by a POSIX TZ string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixTimeZone {
+    pub standard: LocalTimeType,
+    pub daylight_saving: Option<DaylightSaving>,
+}
+
+impl PosixTimeZone {
+    /// The start and end of daylight saving time in a year, as Unix timestamps
+    fn transitions(&self, year: i32) -> Option<(i64, i64)> {
+        let dst = self.daylight_saving.as_ref()?;
+        let start = dst.start.rule.day(year) * 86400 + i64::from(dst.start.time)
+            - i64::from(self.standard.utc_offset);
+        let end = dst.end.rule.day(year) * 86400 + i64::from(dst.end.time)
+            - i64::from(dst.local_time_type.utc_offset);
+        Some((start, end))
+    }
+
+    /// The year in standard time of a Unix timestamp
+    fn year(&self, unix_timestamp: i64) -> Option<i32> {
+        let local = unix_timestamp.saturating_add(self.standard.utc_offset.into());
+        let (year, _, _) = calendar::civil_from_days(local.div_euclid(86400));
+        i32::try_from(year).ok()
+    }
+
+    /// The Unix timestamps of the transitions in the years around a Unix timestamp
+    pub(crate) fn transitions_around(&self, unix_timestamp: i64) -> impl Iterator<Item = i64> {
+        let transitions = match self.year(unix_timestamp) {
+            Some(year) if (i32::MIN + 1..i32::MAX).contains(&year) => {
+                [year - 1, year, year + 1].map(|year| self.transitions(year))
+            }
+            _ => [None; 3],
+        };
+        transitions
+            .into_iter()
+            .flatten()
+            .flat_map(|(start, end)| [start, end])
+    }
+
+    /// The local time type at a number of seconds since 1970-01-01T00:00:00Z
+    pub fn local_time_type(&self, unix_timestamp: i64) -> &LocalTimeType {
+        let (Some(dst), Some((start, end))) = (
+            &self.daylight_saving,
+            self.year(unix_timestamp)
+                .and_then(|year| self.transitions(year)),
+        ) else {
+            return &self.standard;
+        };
+        let in_dst = if start < end {
+            (start..end).contains(&unix_timestamp)
+        } else {
+            // Daylight saving time around the new year, on the southern hemisphere
+            !(end..start).contains(&unix_timestamp)
+        };
+        match in_dst {
+            true => &dst.local_time_type,
+            false => &self.standard,
+        }
+    }
+
+    /// The same point in time in the local time of this time zone
+    pub fn to_local<Y: YearDigits>(
+        &self,
+        date_time: PreciseShiftedDateTime<Y>,
+    ) -> Result<PreciseShiftedDateTime<Y>, components::Error> {
+        let local_time_type = self.local_time_type(date_time.unix_timestamp());
+        date_time.to_timeshift(local_time_type.timeshift()?)
+    }
+
+    /// Find the time shift of a local date-time in this time zone
+    pub fn resolve<Y>(
+        &self,
+        date_time: &LocalDateTime<Y>,
+    ) -> Result<LocalResult, components::Error> {
+        self.resolve_local(local_seconds(
+            &date_time.year,
+            date_time.month,
+            date_time.day,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+        ))
+    }
+
+    /// Find the time shift of a local date-time in this time zone
+    pub fn resolve_precise<Y>(
+        &self,
+        date_time: &PreciseLocalDateTime<Y>,
+    ) -> Result<LocalResult, components::Error> {
+        self.resolve_local(local_seconds(
+            &date_time.year,
+            date_time.month,
+            date_time.day,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+        ))
+    }
+
+    fn resolve_local(&self, local: i64) -> Result<LocalResult, components::Error> {
+        resolve_local_seconds(
+            local,
+            self.transitions_around(local - i64::from(self.standard.utc_offset)),
+            |unix_timestamp| self.local_time_type(unix_timestamp).utc_offset,
+        )
+    }
+}
+
+/// Reads a TZ string from left to right
+struct Scanner<'a>(parse_utils::Scanner<'a>);
+
+impl<'a> Deref for Scanner<'a> {
+    type Target = parse_utils::Scanner<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Scanner<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Scanner<'_> {
+    fn number(&mut self, max_digits: usize, max: u16) -> Result<u16, Error> {
+        let start = self.position();
+        let value = self.number_between(1, max_digits)?;
+        match u16::try_from(value) {
+            Ok(value) if value <= max => Ok(value),
+            _ => Err(Error::Syntax { position: start }),
+        }
+    }
+
+    /// A name like `CET`, or `<+1030>` with signs and digits
+    fn name(&mut self) -> Result<String, Error> {
+        let start = self.position();
+        let name = if self.eat(b'<') {
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'-');
+            if !self.eat(b'>') {
+                return Err(self.error());
+            }
+            name
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic())
+        };
+        if name.len() < 3 {
+            return Err(Error::Syntax { position: start });
+        }
+        Ok(str::from_utf8(name).expect("ASCII").to_string())
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds
+    fn time(&mut self, max_hours: u16) -> Result<i32, Error> {
+        let negative = self.eat(b'-');
+        if !negative {
+            self.eat(b'+');
+        }
+        let digits = if max_hours > 99 { 3 } else { 2 };
+        let mut seconds = i32::from(self.number(digits, max_hours)?) * 3600;
+        if self.eat(b':') {
+            seconds += i32::from(self.number(2, 59)?) * 60;
+            if self.eat(b':') {
+                seconds += i32::from(self.number(2, 59)?);
+            }
+        }
+        Ok(if negative { -seconds } else { seconds })
+    }
+
+    fn rule(&mut self) -> Result<Rule, Error> {
+        if self.eat(b'J') {
+            let start = self.position();
+            match self.number(3, 365)? {
+                0 => Err(Error::Syntax { position: start }),
+                day => Ok(Rule::Julian(day)),
+            }
+        } else if self.eat(b'M') {
+            let month = self.number(2, 12)? as u8;
+            if month == 0 || !self.eat(b'.') {
+                return Err(self.error());
+            }
+            let week = self.number(1, 5)? as u8;
+            if week == 0 || !self.eat(b'.') {
+                return Err(self.error());
+            }
+            let weekday = self.number(1, 6)? as u8;
+            Ok(Rule::MonthWeekday {
+                month,
+                week,
+                weekday,
+            })
+        } else {
+            Ok(Rule::Day(self.number(3, 365)?))
+        }
+    }
+
+    fn transition(&mut self) -> Result<Transition, Error> {
+        let rule = self.rule()?;
+        let time = if self.eat(b'/') {
+            self.time(167)?
+        } else {
+            DEFAULT_TRANSITION_TIME
+        };
+        Ok(Transition { rule, time })
+    }
+}
+
+/// Transitions happen at 02:00:00 local time unless given otherwise
+const DEFAULT_TRANSITION_TIME: i32 = 7200;
+
+impl str::FromStr for PosixTimeZone {
+    type Err = Error;
+
+    /// Parse a TZ string
+    ///
+    /// Without rules, daylight saving time follows the United States rules
+    /// `M3.2.0,M11.1.0`, like most C libraries do.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner(parse_utils::Scanner::new(s.as_bytes()));
+        let abbreviation = scanner.name()?;
+        let utc_offset = -scanner.time(24)?;
+        let standard = LocalTimeType {
+            utc_offset,
+            is_dst: false,
+            abbreviation,
+        };
+        let daylight_saving = match scanner.peek() {
+            None => None,
+            Some(_) => {
+                let abbreviation = scanner.name()?;
+                let utc_offset = match scanner.peek() {
+                    Some(b',') | None => standard.utc_offset + 3600,
+                    Some(_) => -scanner.time(24)?,
+                };
+                let (start, end) = if scanner.eat(b',') {
+                    let start = scanner.transition()?;
+                    scanner.expect(b",")?;
+                    (start, scanner.transition()?)
+                } else {
+                    let rule = |month, week| Transition {
+                        rule: Rule::MonthWeekday {
+                            month,
+                            week,
+                            weekday: 0,
+                        },
+                        time: DEFAULT_TRANSITION_TIME,
+                    };
+                    (rule(3, 2), rule(11, 1))
+                };
+                Some(DaylightSaving {
+                    local_time_type: LocalTimeType {
+                        utc_offset,
+                        is_dst: true,
+                        abbreviation,
+                    },
+                    start,
+                    end,
+                })
+            }
+        };
+        scanner.end()?;
+        Ok(Self {
+            standard,
+            daylight_saving,
+        })
+    }
+}
+
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    if name.bytes().all(|c| c.is_ascii_alphabetic()) {
+        write!(f, "{}", name)
+    } else {
+        write!(f, "<{}>", name)
+    }
+}
+
+/// Seconds as `[-]h[:mm[:ss]]`
+fn write_time(f: &mut fmt::Formatter<'_>, seconds: i32) -> fmt::Result {
+    if seconds < 0 {
+        write!(f, "-")?;
+    }
+    let seconds = seconds.unsigned_abs();
+    write!(f, "{}", seconds / 3600)?;
+    if !seconds.is_multiple_of(3600) {
+        write!(f, ":{:02}", seconds / 60 % 60)?;
+        if !seconds.is_multiple_of(60) {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Julian(day) => write!(f, "J{}", day),
+            Rule::Day(day) => write!(f, "{}", day),
+            Rule::MonthWeekday {
+                month,
+                week,
+                weekday,
+            } => write!(f, "M{}.{}.{}", month, week, weekday),
+        }
+    }
+}
+
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rule)?;
+        if self.time != DEFAULT_TRANSITION_TIME {
+            write!(f, "/")?;
+            write_time(f, self.time)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PosixTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_name(f, &self.standard.abbreviation)?;
+        write_time(f, -self.standard.utc_offset)?;
+        if let Some(dst) = &self.daylight_saving {
+            write_name(f, &dst.local_time_type.abbreviation)?;
+            if dst.local_time_type.utc_offset != self.standard.utc_offset + 3600 {
+                write_time(f, -dst.local_time_type.utc_offset)?;
+            }
+            write!(f, ",{},{}", dst.start, dst.end)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, PosixTimeZone, Rule};
+    use crate::{
+        calendar,
+        combined::{LocalDateTime, PreciseShiftedDateTime},
+        components::{Hour, Minute, Timeshift},
+        tz::LocalResult,
+    };
+
+    fn offset(hours: i32, minutes: u64) -> Timeshift {
+        Timeshift::offset(
+            hours >= 0,
+            Hour::new(hours.unsigned_abs().into()).unwrap(),
+            Minute::new(minutes).unwrap(),
+        )
+    }
+
+    fn resolve(zone: &PosixTimeZone, s: &str) -> LocalResult {
+        let local: LocalDateTime = s.parse().unwrap();
+        zone.resolve(&local).unwrap()
+    }
+
+    fn abbreviation(zone: &PosixTimeZone, s: &str) -> String {
+        let utc: PreciseShiftedDateTime = s.parse().unwrap();
+        zone.local_time_type(utc.unix_timestamp())
+            .abbreviation
+            .clone()
+    }
+
+    #[test]
+    fn test_parse() {
+        for (s, formatted) in [
+            ("CET-1CEST,M3.5.0,M10.5.0/3", "CET-1CEST,M3.5.0,M10.5.0/3"),
+            ("EST5EDT,M3.2.0,M11.1.0", "EST5EDT,M3.2.0,M11.1.0"),
+            ("EST5EDT", "EST5EDT,M3.2.0,M11.1.0"),
+            ("UTC0", "UTC0"),
+            (
+                "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+                "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+            ),
+            (
+                "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1",
+                "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1",
+            ),
+            ("EST+5EDT,0/0,J365/25", "EST5EDT,0/0,J365/25"),
+            ("IST-2IDT,M3.4.4/26,M10.5.0", "IST-2IDT,M3.4.4/26,M10.5.0"),
+            ("<+0530>-05:30", "<+0530>-5:30"),
+            ("XXX-0:00:01", "XXX-0:00:01"),
+        ] {
+            let zone: PosixTimeZone = s.parse().unwrap();
+            assert_eq!(zone.to_string(), formatted);
+        }
+
+        let zone: PosixTimeZone = "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0".parse().unwrap();
+        assert_eq!(zone.standard.utc_offset, 37800);
+        assert_eq!(zone.standard.abbreviation, "+1030");
+        let dst = zone.daylight_saving.unwrap();
+        assert_eq!(dst.local_time_type.utc_offset, 39600);
+        assert!(dst.local_time_type.is_dst);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (s, position) in [
+            ("", 0),
+            ("CE-1", 0),
+            ("CET", 3),
+            ("CET-25", 4),
+            ("CET-1CEST,M3.5.0", 16),
+            ("CET-1CEST,M13.5.0,M10.5.0", 11),
+            ("CET-1CEST,M3.6.0,M10.5.0", 13),
+            ("CET-1CEST,M3.5.7,M10.5.0", 15),
+            ("CET-1CEST,J0,M10.5.0", 11),
+            ("CET-1CEST,366,M10.5.0", 10),
+            ("CET-1CEST,M3.5.0/168,M10.5.0", 17),
+            ("CET-1CEST,M3.5.0,M10.5.0/3x", 26),
+            ("<+01-1", 6),
+            (":Europe/Amsterdam", 0),
+        ] {
+            let result = s.parse::<PosixTimeZone>();
+            assert!(
+                matches!(result, Err(Error::Syntax { position: p }) if p == position),
+                "{} {:?}",
+                s,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_day() {
+        let date = |days| calendar::civil_from_days(days);
+        let last_sunday_of_march = Rule::MonthWeekday {
+            month: 3,
+            week: 5,
+            weekday: 0,
+        };
+        assert_eq!(date(last_sunday_of_march.day(2023)), (2023, 3, 26));
+        assert_eq!(date(last_sunday_of_march.day(2024)), (2024, 3, 31));
+        let second_sunday_of_march = Rule::MonthWeekday {
+            month: 3,
+            week: 2,
+            weekday: 0,
+        };
+        assert_eq!(date(second_sunday_of_march.day(2023)), (2023, 3, 12));
+        assert_eq!(date(Rule::Julian(60).day(2023)), (2023, 3, 1));
+        assert_eq!(date(Rule::Julian(60).day(2024)), (2024, 3, 1));
+        assert_eq!(date(Rule::Day(59).day(2023)), (2023, 3, 1));
+        assert_eq!(date(Rule::Day(59).day(2024)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_local_time_type() {
+        let zone: PosixTimeZone = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+        assert_eq!(abbreviation(&zone, "2023-03-26T00:59:59Z"), "CET");
+        assert_eq!(abbreviation(&zone, "2023-03-26T01:00:00Z"), "CEST");
+        assert_eq!(abbreviation(&zone, "2023-10-29T00:59:59Z"), "CEST");
+        assert_eq!(abbreviation(&zone, "2023-10-29T01:00:00Z"), "CET");
+        assert_eq!(abbreviation(&zone, "2023-12-31T23:30:00Z"), "CET");
+
+        let zone: PosixTimeZone = "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0".parse().unwrap();
+        assert_eq!(abbreviation(&zone, "2023-01-01T00:00:00Z"), "+11");
+        assert_eq!(abbreviation(&zone, "2023-06-01T00:00:00Z"), "+1030");
+        assert_eq!(abbreviation(&zone, "2023-12-31T23:00:00Z"), "+11");
+
+        // Daylight saving time all year
+        let zone: PosixTimeZone = "EST5EDT,0/0,J365/25".parse().unwrap();
+        assert_eq!(abbreviation(&zone, "2023-01-01T04:30:00Z"), "EDT");
+        assert_eq!(abbreviation(&zone, "2023-07-01T00:00:00Z"), "EDT");
+        assert_eq!(abbreviation(&zone, "2023-12-31T23:59:59Z"), "EDT");
+
+        let zone: PosixTimeZone = "UTC0".parse().unwrap();
+        assert_eq!(zone.local_time_type(i64::MAX).abbreviation, "UTC");
+        let zone: PosixTimeZone = "EST5EDT".parse().unwrap();
+        assert_eq!(zone.local_time_type(i64::MAX).abbreviation, "EST");
+        assert_eq!(zone.local_time_type(i64::MIN).abbreviation, "EST");
+    }
+
+    #[test]
+    fn test_resolve() {
+        let zone: PosixTimeZone = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+        assert_eq!(
+            resolve(&zone, "2023-09-17T11:08:58"),
+            LocalResult::Single(offset(2, 0))
+        );
+        assert_eq!(
+            resolve(&zone, "2023-03-26T02:30:00"),
+            LocalResult::Gap {
+                before: offset(1, 0),
+                after: offset(2, 0)
+            }
+        );
+        assert_eq!(
+            resolve(&zone, "2023-10-29T02:30:00"),
+            LocalResult::Fold {
+                earlier: offset(2, 0),
+                later: offset(1, 0)
+            }
+        );
+        assert_eq!(
+            resolve(&zone, "2023-10-29T03:00:00"),
+            LocalResult::Single(offset(1, 0))
+        );
+
+        let zone: PosixTimeZone = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        assert_eq!(
+            resolve(&zone, "2023-11-05T01:30:00"),
+            LocalResult::Fold {
+                earlier: offset(-4, 0),
+                later: offset(-5, 0)
+            }
+        );
+        assert_eq!(
+            resolve(&zone, "2023-03-12T02:30:00"),
+            LocalResult::Gap {
+                before: offset(-5, 0),
+                after: offset(-4, 0)
+            }
+        );
+
+        // The switches at the new year cancel out
+        let zone: PosixTimeZone = "EST5EDT,0/0,J365/25".parse().unwrap();
+        assert_eq!(
+            resolve(&zone, "2024-01-01T00:30:00"),
+            LocalResult::Single(offset(-4, 0))
+        );
+
+        let utc: PreciseShiftedDateTime = "2023-03-26T01:30:00Z".parse().unwrap();
+        let zone: PosixTimeZone = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+        assert_eq!(
+            zone.to_local(utc).unwrap().to_string(),
+            "2023-03-26T03:30:00.0+02:00"
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Internet Message Format dates as defined by RFC 5322
+//!
+//! Parses the `Date:` header of e-mail, like `Sun, 17 Sep 2023 09:08:58 +0200`,
+//! including the obsolete syntax of section 4.3: two- and three-digit years,
+//! zone names like `GMT` and `EST`, and comments and folding whitespace
+//! between the parts. The day of the week is optional, but must match the date
+//! when given.
+//!
+//! A zone of `-0000` means the local offset is unknown, and is kept as a
+//! negative zero offset. The military single-letter zones were defined
+//! inconsistently, so as RFC 5322 recommends they are read as `-0000` too.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::rfc5322;
+//! let dt = rfc5322::parse("Sun, 17 Sep 23 09:08:58 EDT (Eastern Daylight Time)").unwrap();
+//! assert_eq!(dt.to_string(), "2023-09-17T09:08:58-04:00");
+//! assert_eq!(rfc5322::format(&dt), "Sun, 17 Sep 2023 09:08:58 -0400");
+//! ```
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    combined::{check_time, LocalDate, ShiftedDateTime},
+    components::{self, Day, Hour, Minute, Month, Nanosecond, Second, Timeshift, Weekday, Year},
+    parse_utils::{self, SyntaxError},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The date does not follow the RFC 5322 syntax, starting at this byte
+    Syntax {
+        position: usize,
+    },
+    /// The day of the week does not match the date
+    Weekday,
+    DateTime(components::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Syntax { position } => write!(f, "invalid date at byte {}", position),
+            Error::Weekday => write!(f, "day of the week does not match the date"),
+            Error::DateTime(e) => write!(f, "invalid date-time: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DateTime(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::DateTime(value)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(value: SyntaxError) -> Self {
+        Error::Syntax {
+            position: value.position,
+        }
+    }
+}
+
+/// Names of the days of the week, from Monday
+pub(crate) const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Names of the months, from January
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+pub(crate) fn day_name(weekday: Weekday) -> &'static str {
+    DAY_NAMES[weekday as usize - 1]
+}
+
+pub(crate) fn month_name(month: Month) -> &'static str {
+    MONTH_NAMES[usize::from(u8::from(month)) - 1]
+}
+
+/// The index of a name in a list of names, ignoring case
+pub(crate) fn find_name(names: &[&str], name: &[u8]) -> Option<usize> {
+    names
+        .iter()
+        .position(|candidate| candidate.as_bytes().eq_ignore_ascii_case(name))
+}
+
+/// The time shift of a zone name of the obsolete syntax
+fn obsolete_zone(name: &[u8]) -> Option<Timeshift> {
+    // Hours west of UTC
+    let hours = match name.to_ascii_uppercase().as_slice() {
+        b"UT" | b"GMT" => return Some(Timeshift::Utc),
+        b"EDT" => 4,
+        b"EST" | b"CDT" => 5,
+        b"CST" | b"MDT" => 6,
+        b"MST" | b"PDT" => 7,
+        b"PST" => 8,
+        [c] if c.is_ascii_alphabetic() && *c != b'J' => 0,
+        _ => return None,
+    };
+    let hours = Hour::new(hours).expect("valid hour");
+    let minutes = Minute::new(0).expect("valid minute");
+    Some(Timeshift::offset(false, hours, minutes))
+}
+
+/// Reads a date from left to right
+struct Scanner<'a>(parse_utils::Scanner<'a>);
+
+impl<'a> Deref for Scanner<'a> {
+    type Target = parse_utils::Scanner<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Scanner<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Scanner<'_> {
+    /// Skip folding whitespace, a line break only counting when followed by whitespace
+    fn skip_fws(&mut self) -> bool {
+        let start = self.position();
+        loop {
+            self.take_while(|c| c == b' ' || c == b'\t');
+            let rest = self.rest();
+            if rest.starts_with(b"\r\n ") || rest.starts_with(b"\r\n\t") {
+                self.advance(2);
+            } else {
+                break;
+            }
+        }
+        self.position() > start
+    }
+
+    /// Skip comments and folding whitespace, and whether anything was skipped
+    fn skip_cfws(&mut self) -> Result<bool, Error> {
+        let start = self.position();
+        while self.skip_fws() || self.peek() == Some(b'(') {
+            if self.eat(b'(') {
+                let mut depth = 1;
+                while depth > 0 {
+                    match self.peek() {
+                        Some(b'(') => depth += 1,
+                        Some(b')') => depth -= 1,
+                        Some(b'\\') => self.advance(1),
+                        Some(_) => {}
+                        None => return Err(self.error()),
+                    }
+                    self.advance(1);
+                }
+            }
+        }
+        Ok(self.position() > start)
+    }
+
+    /// Comments and folding whitespace that separate two parts
+    fn separator(&mut self) -> Result<(), Error> {
+        match self.skip_cfws()? {
+            true => Ok(()),
+            false => Err(self.error()),
+        }
+    }
+
+    fn name(&mut self, names: &[&str]) -> Result<usize, Error> {
+        let start = self.position();
+        let name = self.take_while(|c| c.is_ascii_alphabetic());
+        find_name(names, name).ok_or(Error::Syntax { position: start })
+    }
+
+    /// A two-digit time component, optionally surrounded by comments
+    fn time_component(&mut self) -> Result<u64, Error> {
+        self.skip_cfws()?;
+        let value = self.number_between(2, 2)?;
+        self.skip_cfws()?;
+        Ok(value)
+    }
+
+    fn zone(&mut self) -> Result<Timeshift, Error> {
+        let start = self.position();
+        let non_negative = match self.peek() {
+            Some(b'+') => true,
+            Some(b'-') => false,
+            _ => {
+                let name = self.take_while(|c| c.is_ascii_alphabetic());
+                return obsolete_zone(name).ok_or(Error::Syntax { position: start });
+            }
+        };
+        self.advance(1);
+        let digits = self.number_between(4, 4)?;
+        let hours = Hour::new(digits / 100).ok().filter(|h| u8::from(*h) < 24);
+        let minutes = Minute::new(digits % 100).ok().filter(|m| u8::from(*m) < 60);
+        match (hours, minutes) {
+            (Some(hours), Some(minutes)) => Ok(Timeshift::offset(non_negative, hours, minutes)),
+            _ => Err(Error::Syntax { position: start }),
+        }
+    }
+}
+
+/// Parse an RFC 5322 date-time
+pub fn parse(s: &str) -> Result<ShiftedDateTime, Error> {
+    let mut scanner = Scanner(parse_utils::Scanner::new(s.as_bytes()));
+    scanner.skip_cfws()?;
+    let weekday = match scanner.peek() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            let weekday = scanner.name(&DAY_NAMES)?;
+            scanner.skip_cfws()?;
+            scanner.expect(b",")?;
+            scanner.skip_cfws()?;
+            Some(Weekday::new(weekday as u64 + 1)?)
+        }
+        _ => None,
+    };
+
+    let day = scanner.number_between(1, 2)?;
+    scanner.separator()?;
+    let month = scanner.name(&MONTH_NAMES)? as u64 + 1;
+    scanner.separator()?;
+    let year_position = scanner.position();
+    let year = scanner.number_between(2, 9)?;
+    let year = match (scanner.position() - year_position, year) {
+        // Two-digit years from 50 are in the 1900s, three-digit years count from 1900
+        (2, 0..=49) => year + 2000,
+        (2 | 3, _) => year + 1900,
+        _ => year,
+    };
+    scanner.separator()?;
+
+    let hour = scanner.time_component()?;
+    scanner.expect(b":")?;
+    let minute = scanner.time_component()?;
+    let second = match scanner.eat(b':') {
+        true => scanner.time_component()?,
+        false => 0,
+    };
+    let zone = scanner.zone()?;
+    scanner.skip_cfws()?;
+    scanner.end()?;
+
+    let date = LocalDate::new(
+        Year::new(year.try_into().map_err(|_| components::Error::Range)?)?,
+        Month::new(month)?,
+        Day::new(day)?,
+    );
+    if u8::from(date.day) > date.days_in_month() {
+        return Err(components::Error::Range.into());
+    }
+    if weekday.is_some_and(|weekday| weekday != date.weekday()) {
+        return Err(Error::Weekday);
+    }
+    if hour > 23 || minute > 59 {
+        return Err(components::Error::Range.into());
+    }
+    let (hour, minute, second) = (Hour::new(hour)?, Minute::new(minute)?, Second::new(second)?);
+    check_time(hour, minute, second, Nanosecond::new(0)?, Some(zone))?;
+    Ok(ShiftedDateTime::new(
+        date.year, date.month, date.day, hour, minute, second, zone,
+    ))
+}
+
+/// Format a date-time in the canonical RFC 5322 form
+pub fn format(date_time: &ShiftedDateTime) -> String {
+    let date = LocalDate::new(date_time.year, date_time.month, date_time.day);
+    format!(
+        "{}, {} {} {} {}:{}:{} {}",
+        day_name(date.weekday()),
+        u8::from(date_time.day),
+        month_name(date_time.month),
+        date_time.year,
+        date_time.hour,
+        date_time.minute,
+        date_time.second,
+        Numeric(date_time.timeshift),
+    )
+}
+
+/// A time shift as `+hhmm`
+pub(crate) struct Numeric(pub(crate) Timeshift);
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Timeshift::Utc => write!(f, "+0000"),
+            Timeshift::Offset {
+                non_negative,
+                hours,
+                minutes,
+            } => {
+                let sign = if non_negative { '+' } else { '-' };
+                write!(f, "{}{}{}", sign, hours, minutes)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse, Error};
+    use crate::components;
+
+    #[test]
+    fn test_parse() {
+        for (s, expected) in [
+            (
+                "Sun, 17 Sep 2023 09:08:58 +0200",
+                "2023-09-17T09:08:58+02:00",
+            ),
+            ("17 Sep 2023 09:08:58 +0200", "2023-09-17T09:08:58+02:00"),
+            (
+                "Tue, 1 Jul 2003 10:52:37 +0200",
+                "2003-07-01T10:52:37+02:00",
+            ),
+            ("Fri, 21 Nov 1997 09:55 -0600", "1997-11-21T09:55:00-06:00"),
+            ("sun, 17 sep 2023 09:08:58 gmt", "2023-09-17T09:08:58Z"),
+            ("Sun, 17 Sep 2023 09:08:58 UT", "2023-09-17T09:08:58Z"),
+            (
+                "Sun, 17 Sep 2023 09:08:58 -0000",
+                "2023-09-17T09:08:58-00:00",
+            ),
+            ("Sun, 17 Sep 2023 09:08:58 PDT", "2023-09-17T09:08:58-07:00"),
+            ("Sun, 17 Sep 2023 09:08:58 Z", "2023-09-17T09:08:58-00:00"),
+            ("Sun, 17 Sep 23 09:08:58 EDT", "2023-09-17T09:08:58-04:00"),
+            ("Fri, 21 Nov 97 09:55:06 CST", "1997-11-21T09:55:06-06:00"),
+            ("Fri, 21 Nov 097 09:55:06 MST", "1997-11-21T09:55:06-07:00"),
+            (
+                "Sat, 1 Jan 2000 00:00:00 +0000",
+                "2000-01-01T00:00:00+00:00",
+            ),
+            ("Sat, 1 Jan 00 00:00:00 +0000", "2000-01-01T00:00:00+00:00"),
+            (
+                "Sat, 31 Dec 2016 23:59:60 +0000",
+                "2016-12-31T23:59:60+00:00",
+            ),
+            (
+                "Thu,\r\n 13\r\n  Feb\r\n    1969\r\n 23:32\r\n   -0330 (Newfoundland Time)",
+                "1969-02-13T23:32:00-03:30",
+            ),
+            (
+                "(comment (nested \\) )) Sun (day) , 17 Sep 2023 09 : 08 : 58 +0200 (CEST)",
+                "2023-09-17T09:08:58+02:00",
+            ),
+        ] {
+            let dt = parse(s);
+            assert!(dt.is_ok(), "{}: {:?}", s, dt);
+            assert_eq!(dt.unwrap().to_string(), expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (s, position) in [
+            ("", 0),
+            ("Sunday, 17 Sep 2023 09:08:58 +0200", 0),
+            ("Sun 17 Sep 2023 09:08:58 +0200", 4),
+            ("Sun, 17Sep 2023 09:08:58 +0200", 7),
+            ("Sun, 17 September 2023 09:08:58 +0200", 8),
+            ("Sun, 17 Sep 2023 09:08:58", 25),
+            ("Sun, 17 Sep 2023 09:08:58 +02:00", 27),
+            ("Sun, 17 Sep 2023 09:08:58 +2400", 26),
+            ("Sun, 17 Sep 2023 09:08:58 +0060", 26),
+            ("Sun, 17 Sep 2023 09:08:58 CET", 26),
+            ("Sun, 17 Sep 2023 09:08:58 J", 26),
+            ("Sun, 17 Sep 2023 9:08:58 +0200", 17),
+            ("Sun, 17 Sep 2023 09:08:58 +0200 (unclosed", 41),
+            ("Sun, 17 Sep 2023 09:08:58 +0200 x", 32),
+            ("Sun, 17 Sep 2023 09:08:58 +0200\r\n", 31),
+        ] {
+            let result = parse(s);
+            assert!(
+                matches!(result, Err(Error::Syntax { position: p }) if p == position),
+                "{}: {:?}",
+                s,
+                result
+            );
+        }
+
+        assert!(matches!(
+            parse("Mon, 17 Sep 2023 09:08:58 +0200"),
+            Err(Error::Weekday)
+        ));
+        for s in [
+            "Thu, 30 Feb 2023 09:08:58 +0200",
+            "Sun, 17 Sep 2023 24:00:00 +0200",
+            "Sun, 17 Sep 2023 09:60:00 +0200",
+            "Sun, 17 Sep 2023 09:08:60 +0200",
+            "17 Sep 12023 09:08:58 +0200",
+        ] {
+            let result = parse(s);
+            assert!(
+                matches!(result, Err(Error::DateTime(_))),
+                "{}: {:?}",
+                s,
+                result
+            );
+        }
+        assert!(matches!(
+            parse("Sun, 17 Sep 2023 09:08:60 +0200"),
+            Err(Error::DateTime(components::Error::LeapSecond))
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        for (s, expected) in [
+            (
+                "Sun, 17 Sep 2023 09:08:58 +0200",
+                "Sun, 17 Sep 2023 09:08:58 +0200",
+            ),
+            ("17 Sep 23 09:08 GMT", "Sun, 17 Sep 2023 09:08:00 +0000"),
+            (
+                "1 jul 2003 10:52:37 -0000",
+                "Tue, 1 Jul 2003 10:52:37 -0000",
+            ),
+            (
+                "Fri, 21 Nov 97 09:55:06 CST",
+                "Fri, 21 Nov 1997 09:55:06 -0600",
+            ),
+        ] {
+            assert_eq!(format(&parse(s).unwrap()), expected);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Find date-times in free text
+//!
+//! [`timestamps`] returns an iterator over the RFC 3339 date-times in a byte
+//! slice, like the one in `[2023-09-17 09:08:58Z] GET /`, with the byte range
+//! each one was found at. The separator between date and time can be `T`, `t`
+//! or a space, and the fraction and offset are optional, so the values are
+//! [`AnyDateTime`]s. Only the extended format with `-` and `:` is found, so
+//! the ISO 8601 basic format `20230917T090858Z` is not.
+//!
+//! A date-time has to start and end at a digit boundary, so `12023-09-17...`
+//! is not found. A `.` without digits, like the one ending a sentence, is left
+//! out of the match. An offset that is cut off, like the `+02` in
+//! `2023-09-17T09:08:58+02`, rejects the date-time, since reading it as a
+//! local time would drop the offset.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::scan::timestamps;
+//! let line = b"[2023-09-17 09:08:58Z] GET / took 12ms, retried at 2023-09-17T09:09:01.5.";
+//! let found: Vec<_> = timestamps(line).collect();
+//! assert_eq!(found.len(), 2);
+//! assert_eq!(found[0].span, 1..21);
+//! assert_eq!(found[1].date_time.to_string(), "2023-09-17T09:09:01.5");
+//! assert_eq!(&line[found[1].span.clone()], b"2023-09-17T09:09:01.5");
+//! ```
+
+use core::ops::Range;
+
+use crate::{
+    combined::AnyDateTime,
+    parse::{ParseContext, Parser},
+};
+
+/// A date-time found by [`timestamps`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found {
+    pub date_time: AnyDateTime,
+    /// Where the date-time is in the input
+    pub span: Range<usize>,
+}
+
+/// Iterator returned by [`timestamps`]
+#[derive(Debug, Clone)]
+pub struct Timestamps<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+/// Find all RFC 3339 date-times in `data`, from left to right
+pub fn timestamps(data: &[u8]) -> Timestamps<'_> {
+    Timestamps { data, position: 0 }
+}
+
+/// The length of an offset at the start of `data`, or 0 when there is none
+fn offset_len(data: &[u8]) -> usize {
+    match data {
+        [b'Z' | b'z', ..] => 1,
+        [b'+' | b'-', h1, h2, b':', m1, m2, ..]
+            if [h1, h2, m1, m2].iter().all(|c| c.is_ascii_digit()) =>
+        {
+            6
+        }
+        _ => 0,
+    }
+}
+
+/// Parse a date-time at the start of `data`, returning it and its length
+fn parse_at(data: &[u8]) -> Option<(AnyDateTime, usize)> {
+    let mut parser: Parser = ParseContext::new_rfc3339().into_parser();
+    let rest = parser.parse_local_date_time(data).ok()?;
+    let rest = match rest {
+        [b'.', digit, ..] if digit.is_ascii_digit() => {
+            let rest = parser.parse_fractional_separator(rest).ok()?;
+            parser.parse_fractional_seconds(rest).ok()?
+        }
+        _ => rest,
+    };
+    let rest = match offset_len(rest) {
+        // An incomplete offset, which can't be left out like a `.`
+        0 if matches!(rest, [b'+' | b'-', digit, ..] if digit.is_ascii_digit()) => return None,
+        0 => rest,
+        n => {
+            let after = parser.parse_timezone_offset(&rest[..n]).ok()?;
+            debug_assert!(after.is_empty());
+            &rest[n..]
+        }
+    };
+    if rest.first().is_some_and(u8::is_ascii_digit) {
+        return None;
+    }
+    let date_time = parser.build_any_date_time().ok()?;
+    Some((date_time, data.len() - rest.len()))
+}
+
+impl<'a> Iterator for Timestamps<'a> {
+    type Item = Found;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.data.len() {
+            let start = self.position;
+            let Some(offset) = self.data[start..].iter().position(u8::is_ascii_digit) else {
+                self.position = self.data.len();
+                return None;
+            };
+            let start = start + offset;
+            // Skip the rest of a run of digits that can't start a date-time
+            let digits = self.data[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            self.position = start + digits;
+            let at_boundary = start == 0 || !self.data[start - 1].is_ascii_digit();
+            if digits != 4 || !at_boundary || self.data.get(start + 4) != Some(&b'-') {
+                continue;
+            }
+            if let Some((date_time, len)) = parse_at(&self.data[start..]) {
+                self.position = start + len;
+                return Some(Found {
+                    date_time,
+                    span: start..start + len,
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timestamps;
+    use crate::AnyDateTime;
+
+    fn find(data: &str) -> Vec<(String, &str)> {
+        timestamps(data.as_bytes())
+            .map(|found| (found.date_time.to_string(), &data[found.span]))
+            .collect()
+    }
+
+    #[test]
+    fn test_timestamps() {
+        assert_eq!(
+            find("[2023-09-17 09:08:58Z] GET /"),
+            [("2023-09-17T09:08:58Z".to_string(), "2023-09-17 09:08:58Z")]
+        );
+        assert_eq!(
+            find("a=2023-09-17t09:08:58.123+02:00,b=2023-09-18T10:00:00"),
+            [
+                (
+                    "2023-09-17T09:08:58.123+02:00".to_string(),
+                    "2023-09-17t09:08:58.123+02:00"
+                ),
+                ("2023-09-18T10:00:00".to_string(), "2023-09-18T10:00:00"),
+            ]
+        );
+        assert_eq!(
+            find("ends at 2023-09-17T09:08:58. Then 2023-09-17T09:08:58+02"),
+            [("2023-09-17T09:08:58".to_string(), "2023-09-17T09:08:58")]
+        );
+        assert_eq!(
+            find("2023-09-17T09:08:58 - 2023-09-17T10:00:00-"),
+            [
+                ("2023-09-17T09:08:58".to_string(), "2023-09-17T09:08:58"),
+                ("2023-09-17T10:00:00".to_string(), "2023-09-17T10:00:00"),
+            ]
+        );
+        let found: Vec<_> = timestamps(b"x 2023-09-17 09:08:58.5z").collect();
+        assert!(matches!(found[0].date_time, AnyDateTime::PreciseShifted(_)));
+        assert_eq!(found[0].span, 2..24);
+    }
+
+    #[test]
+    fn test_no_timestamps() {
+        for s in [
+            "",
+            "no dates here",
+            "2023-09-17",
+            "12023-09-17T09:08:58Z",
+            "2023-09-17T09:08:589",
+            "2023-13-17T09:08:58Z",
+            "2023-09-17X09:08:58Z",
+            "2023-09-17T09:08",
+            "2023-09-17T09:08:58+02",
+            "2023-09-17T09:08:58-0200",
+            "2023-09-17T09:08:58+2:00",
+            "id 20230917T090858Z",
+        ] {
+            assert_eq!(find(s), [], "{}", s);
+        }
+        assert_eq!(find("2023-13-17T09:08:58Z 2023-09-17T09:08:58Z").len(), 1);
+    }
+}
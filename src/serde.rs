@@ -0,0 +1,360 @@
+//! Serialization with serde, enabled by the `serde` feature
+//!
+//! All date, time, time shift and duration types serialize as the strings
+//! that their [`Display`](core::fmt::Display) implementations write, and
+//! deserialize with their [`FromStr`](core::str::FromStr) implementations.
+//!
+//! The modules below can be used with `#[serde(with = "...")]` to use a
+//! different representation for a [`PreciseShiftedDateTime`] field.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::PreciseShiftedDateTime;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Event {
+//!     #[serde(with = "datetimeparse::serde::unix_timestamp")]
+//!     created: PreciseShiftedDateTime,
+//!     #[serde(with = "datetimeparse::serde::iso8601")]
+//!     starts: PreciseShiftedDateTime,
+//! }
+//! ```
+
+use core::{fmt, marker::PhantomData, str};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    combined::{
+        AnyDateTime, LocalDate, LocalDateTime, LocalTime, PreciseLocalDateTime, PreciseLocalTime,
+        PreciseShiftedDateTime, ShiftedDateTime,
+    },
+    components::{
+        Day, Hour, Minute, Month, Nanosecond, Second, Timeshift, Week, Weekday, Year, YearDigits,
+    },
+    duration::{
+        DayDuration, Duration, HourDuration, MinuteDuration, MonthDuration, SecondDuration,
+        WeekDuration, YearDuration,
+    },
+    ixdtf::AnnotatedDateTime,
+    parse::{parse_complete, ParseContext, Parser},
+};
+
+/// Deserializes a string with [`str::FromStr`]
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: str::FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+macro_rules! impl_serde {
+    ($type:ident<Y>, $expecting:literal) => {
+        impl<Y> Serialize for $type<Y>
+        where
+            Year<Y>: fmt::Display,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de, Y: YearDigits> Deserialize<'de> for $type<Y> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(FromStrVisitor::new($expecting))
+            }
+        }
+    };
+    ($type:ident, $expecting:literal) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(FromStrVisitor::new($expecting))
+            }
+        }
+    };
+}
+
+impl_serde!(LocalDate<Y>, "an RFC 3339 date");
+impl_serde!(LocalTime, "an RFC 3339 time");
+impl_serde!(PreciseLocalTime, "an RFC 3339 time");
+impl_serde!(LocalDateTime<Y>, "an RFC 3339 date-time without offset");
+impl_serde!(
+    PreciseLocalDateTime<Y>,
+    "an RFC 3339 date-time without offset"
+);
+impl_serde!(ShiftedDateTime<Y>, "an RFC 3339 date-time");
+impl_serde!(PreciseShiftedDateTime<Y>, "an RFC 3339 date-time");
+impl_serde!(AnyDateTime<Y>, "an RFC 3339 date-time with optional offset");
+impl_serde!(AnnotatedDateTime<Y>, "an RFC 9557 date-time");
+impl_serde!(Year<Y>, "a year");
+impl_serde!(Month, "a two digit month");
+impl_serde!(Week, "a week like W01");
+impl_serde!(Weekday, "an English weekday like Monday");
+impl_serde!(Day, "a two digit day");
+impl_serde!(Hour, "a two digit hour");
+impl_serde!(Minute, "a two digit minute");
+impl_serde!(Second, "a two digit second");
+impl_serde!(Nanosecond, "a number of nanoseconds");
+impl_serde!(Timeshift, "a time shift like Z or +01:00");
+impl_serde!(YearDuration, "an ISO 8601 duration in years");
+impl_serde!(MonthDuration, "an ISO 8601 duration in months");
+impl_serde!(WeekDuration, "an ISO 8601 duration in weeks");
+impl_serde!(DayDuration, "an ISO 8601 duration in days");
+impl_serde!(HourDuration, "an ISO 8601 duration in hours");
+impl_serde!(MinuteDuration, "an ISO 8601 duration in minutes");
+impl_serde!(SecondDuration, "an ISO 8601 duration in seconds");
+impl_serde!(Duration, "an ISO 8601 duration");
+
+/// Deserializes a date-time by parsing it with a profile
+struct ProfileVisitor<Y> {
+    context: fn() -> ParseContext,
+    expecting: &'static str,
+    marker: PhantomData<Y>,
+}
+
+impl<'de, Y: YearDigits> de::Visitor<'de> for ProfileVisitor<Y> {
+    type Value = PreciseShiftedDateTime<Y>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        // Extended years are serialized with their sign
+        parse_complete(
+            (self.context)().with_year_sign(),
+            v,
+            Parser::<Y>::parse_precise_shifted_date_time,
+            Parser::<Y>::build_precise_shifted_date_time,
+        )
+        .map_err(E::custom)
+    }
+}
+
+/// Strict RFC 3339 strings, with an uppercase `T` and `Z` and no space
+pub mod rfc3339 {
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{Deserializer, Serializer};
+
+    use super::ProfileVisitor;
+    use crate::{
+        combined::PreciseShiftedDateTime,
+        components::{Year, YearDigits},
+        parse::ParseContext,
+    };
+
+    pub fn serialize<Y, S>(
+        date_time: &PreciseShiftedDateTime<Y>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Year<Y>: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(date_time)
+    }
+
+    pub fn deserialize<'de, Y, D>(deserializer: D) -> Result<PreciseShiftedDateTime<Y>, D::Error>
+    where
+        Y: YearDigits,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ProfileVisitor {
+            context: ParseContext::new_strict_rfc3339,
+            expecting: "a strict RFC 3339 date-time",
+            marker: PhantomData,
+        })
+    }
+}
+
+/// ISO 8601 strings, which can also be in the basic format like `20230917T090858Z`
+///
+/// Serializing writes the extended format, like [`rfc3339`].
+pub mod iso8601 {
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{Deserializer, Serializer};
+
+    use super::ProfileVisitor;
+    use crate::{
+        combined::PreciseShiftedDateTime,
+        components::{Year, YearDigits},
+        parse::ParseContext,
+    };
+
+    pub fn serialize<Y, S>(
+        date_time: &PreciseShiftedDateTime<Y>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Year<Y>: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(date_time)
+    }
+
+    pub fn deserialize<'de, Y, D>(deserializer: D) -> Result<PreciseShiftedDateTime<Y>, D::Error>
+    where
+        Y: YearDigits,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ProfileVisitor {
+            context: ParseContext::new_iso8601,
+            expecting: "an ISO 8601 date-time",
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Integer seconds since 1970-01-01T00:00:00Z
+///
+/// Serializing fails for date-times with a fraction of a second, instead of
+/// dropping it. Deserialized date-times are in UTC.
+pub mod unix_timestamp {
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    use crate::{
+        combined::PreciseShiftedDateTime,
+        components::{Nanosecond, YearDigits},
+    };
+
+    pub fn serialize<Y, S>(
+        date_time: &PreciseShiftedDateTime<Y>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Y: YearDigits,
+        S: Serializer,
+    {
+        if u32::from(date_time.nanosecond) != 0 {
+            return Err(ser::Error::custom(
+                "a Unix timestamp can't have a fraction of a second",
+            ));
+        }
+        serializer.serialize_i64(date_time.unix_timestamp())
+    }
+
+    pub fn deserialize<'de, Y, D>(deserializer: D) -> Result<PreciseShiftedDateTime<Y>, D::Error>
+    where
+        Y: YearDigits,
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(deserializer)?;
+        PreciseShiftedDateTime::from_unix_timestamp(seconds, Nanosecond::new(0).unwrap())
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        components::{ExtendedYear, Timeshift},
+        duration::Duration,
+        LocalDate, Month, PreciseLocalTime, PreciseShiftedDateTime, Weekday,
+    };
+
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(json: &str) {
+        let value: T = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dt: PreciseShiftedDateTime = "2023-09-17T09:08:58.25+02:00".parse().unwrap();
+        let json = serde_json::to_string(&dt).unwrap();
+        assert_eq!(json, r#""2023-09-17T09:08:58.25+02:00""#);
+        assert_eq!(
+            serde_json::from_str::<PreciseShiftedDateTime>(&json).unwrap(),
+            dt
+        );
+
+        let date: LocalDate<ExtendedYear<6>> = "+012023-09-17".parse().unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#""+012023-09-17""#);
+        assert_eq!(serde_json::from_str::<LocalDate<_>>(&json).unwrap(), date);
+
+        round_trip::<PreciseLocalTime>(r#""09:08:58.5""#);
+        round_trip::<Month>(r#""09""#);
+        round_trip::<Weekday>(r#""Sunday""#);
+        round_trip::<Timeshift>(r#""+05:30""#);
+        round_trip::<Duration>(r#""-P1DT0.5S""#);
+        round_trip::<crate::ixdtf::AnnotatedDateTime>(
+            r#""2023-09-17T09:08:58.0+02:00[Europe/Amsterdam][!u-ca=gregory]""#,
+        );
+
+        assert!(
+            serde_json::from_str::<PreciseShiftedDateTime>(r#""2023-13-01T00:00:00Z""#).is_err()
+        );
+        assert!(serde_json::from_str::<Month>("9").is_err());
+        assert!(serde_json::from_str::<Timeshift>(r#""+24:00""#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde::rfc3339")]
+        strict: PreciseShiftedDateTime,
+        #[serde(with = "crate::serde::iso8601")]
+        lenient: PreciseShiftedDateTime,
+        #[serde(with = "crate::serde::unix_timestamp")]
+        timestamp: PreciseShiftedDateTime,
+    }
+
+    #[test]
+    fn test_with_modules() {
+        let event: Event = serde_json::from_str(
+            r#"{
+                "strict": "2023-09-17T09:08:58Z",
+                "lenient": "20230917T090858.5+0200",
+                "timestamp": 1694941738
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(format!("{}", event.lenient), "2023-09-17T09:08:58.5+02:00");
+        assert_eq!(event.timestamp, event.strict);
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"strict":"2023-09-17T09:08:58.0Z","lenient":"2023-09-17T09:08:58.5+02:00","timestamp":1694941738}"#
+        );
+
+        assert!(serde_json::from_str::<Event>(
+            r#"{"strict": "2023-09-17 09:08:58Z", "lenient": "2023-09-17T09:08:58Z", "timestamp": 0}"#
+        )
+        .is_err());
+        let event = Event {
+            timestamp: event.lenient,
+            ..event
+        };
+        assert!(serde_json::to_string(&event).is_err());
+    }
+}
@@ -0,0 +1,768 @@
+//! Database column types, enabled by the `postgres`, `rusqlite`, `sqlx-postgres`,
+//! `sqlx-sqlite` and `sqlx-mysql` features
+//!
+//! | Type                       | PostgreSQL    | SQLite         | MySQL       |
+//! |----------------------------|---------------|----------------|-------------|
+//! | [`LocalDate`]              | `DATE`        | RFC 3339 text  | `DATE`      |
+//! | [`PreciseLocalTime`]       | `TIME`        | RFC 3339 text  | `TIME`      |
+//! | [`PreciseLocalDateTime`]   | `TIMESTAMP`   | RFC 3339 text  | `DATETIME`  |
+//! | [`PreciseShiftedDateTime`] | `TIMESTAMPTZ` | RFC 3339 text  | `TIMESTAMP` |
+//!
+//! PostgreSQL and MySQL store microseconds, so nanoseconds are truncated when
+//! encoding. Neither supports leap seconds, encoding second 60 fails with
+//! [`Error::LeapSecond`](crate::ComponentError::LeapSecond). A `TIMESTAMPTZ` is always decoded with the UTC time
+//! shift, as PostgreSQL does not store the original offset.
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql"
+))]
+use crate::components::Error;
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+use crate::components::Nanosecond;
+#[cfg(any(
+    feature = "postgres",
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql"
+))]
+use crate::components::Timeshift;
+#[cfg(any(feature = "rusqlite", feature = "sqlx-sqlite"))]
+use crate::components::Year;
+use crate::{
+    combined::{LocalDate, PreciseLocalDateTime, PreciseLocalTime, PreciseShiftedDateTime},
+    components::YearDigits,
+};
+
+/// Days from 1970-01-01 to 2000-01-01, the PostgreSQL epoch
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+const PG_EPOCH_DAYS: i64 = 10957;
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+/// A type that PostgreSQL sends as a big-endian integer in the binary format
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+#[cfg_attr(not(feature = "sqlx-postgres"), allow(dead_code))]
+trait PgBinary: Sized {
+    /// Size of the integer in bytes, 4 or 8
+    const SIZE: usize;
+
+    fn to_pg_int(&self) -> Result<i64, Error>;
+
+    fn from_pg_int(value: i64) -> Result<Self, Error>;
+
+    /// Parse the PostgreSQL text format, which `sqlx` uses for simple queries
+    fn from_pg_text(s: &str) -> Result<Self, Error>;
+
+    fn to_pg_bytes(&self) -> Result<[u8; 8], Error> {
+        let value = self.to_pg_int()?;
+        if Self::SIZE == 4 && i32::try_from(value).is_err() {
+            return Err(Error::Range);
+        }
+        Ok(value.to_be_bytes())
+    }
+
+    fn encode_pg(&self, buf: &mut Vec<u8>) -> Result<(), Error> {
+        buf.extend_from_slice(&self.to_pg_bytes()?[8 - Self::SIZE..]);
+        Ok(())
+    }
+
+    fn decode_pg(raw: &[u8]) -> Result<Self, Error> {
+        let value = match Self::SIZE {
+            4 => i32::from_be_bytes(raw.try_into().map_err(|_| Error::Parse)?).into(),
+            _ => i64::from_be_bytes(raw.try_into().map_err(|_| Error::Parse)?),
+        };
+        // The largest and smallest values are used for `infinity` and `-infinity`
+        let infinity = match Self::SIZE {
+            4 => [i32::MIN.into(), i32::MAX.into()],
+            _ => [i64::MIN, i64::MAX],
+        };
+        if infinity.contains(&value) {
+            return Err(Error::Range);
+        }
+        Self::from_pg_int(value)
+    }
+}
+
+/// Microseconds since midnight, rejecting leap seconds
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+fn pg_time_of_day(time: PreciseLocalTime) -> Result<i64, Error> {
+    let hour: i64 = time.hour.into();
+    let minute: i64 = time.minute.into();
+    let second: i64 = time.second.into();
+    let nanosecond: i64 = time.nanosecond.into();
+    if second == 60 {
+        return Err(Error::LeapSecond);
+    }
+    Ok(((hour * 60 + minute) * 60 + second) * 1_000_000 + nanosecond / 1000)
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+fn from_pg_time_of_day(microseconds: i64) -> Result<PreciseLocalTime, Error> {
+    if !(0..=MICROSECONDS_PER_DAY).contains(&microseconds) {
+        return Err(Error::Range);
+    }
+    let seconds = microseconds / 1_000_000;
+    Ok(PreciseLocalTime::new(
+        (seconds / 3600).try_into()?,
+        (seconds / 60 % 60).try_into()?,
+        (seconds % 60).try_into()?,
+        Nanosecond::new((microseconds % 1_000_000 * 1000) as u64)?,
+    ))
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+fn pg_timestamp<Y>(date: LocalDate<Y>, time: PreciseLocalTime) -> Result<i64, Error> {
+    let time = pg_time_of_day(time)?;
+    (date.days_since_epoch() - PG_EPOCH_DAYS)
+        .checked_mul(MICROSECONDS_PER_DAY)
+        .and_then(|microseconds| microseconds.checked_add(time))
+        .ok_or(Error::Range)
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+fn from_pg_timestamp<Y: YearDigits>(
+    microseconds: i64,
+) -> Result<(LocalDate<Y>, PreciseLocalTime), Error> {
+    let date = LocalDate::from_days_since_epoch(
+        microseconds.div_euclid(MICROSECONDS_PER_DAY) + PG_EPOCH_DAYS,
+    )?;
+    let time = from_pg_time_of_day(microseconds.rem_euclid(MICROSECONDS_PER_DAY))?;
+    Ok((date, time))
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+impl<Y: YearDigits> PgBinary for LocalDate<Y> {
+    const SIZE: usize = 4;
+
+    fn to_pg_int(&self) -> Result<i64, Error> {
+        Ok(self.days_since_epoch() - PG_EPOCH_DAYS)
+    }
+
+    fn from_pg_int(value: i64) -> Result<Self, Error> {
+        Self::from_days_since_epoch(value + PG_EPOCH_DAYS)
+    }
+
+    fn from_pg_text(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+impl PgBinary for PreciseLocalTime {
+    const SIZE: usize = 8;
+
+    fn to_pg_int(&self) -> Result<i64, Error> {
+        pg_time_of_day(*self)
+    }
+
+    fn from_pg_int(value: i64) -> Result<Self, Error> {
+        from_pg_time_of_day(value)
+    }
+
+    fn from_pg_text(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+impl<Y: YearDigits> PgBinary for PreciseLocalDateTime<Y> {
+    const SIZE: usize = 8;
+
+    fn to_pg_int(&self) -> Result<i64, Error> {
+        pg_timestamp(
+            LocalDate::new(self.year, self.month, self.day),
+            PreciseLocalTime::new(self.hour, self.minute, self.second, self.nanosecond),
+        )
+    }
+
+    fn from_pg_int(value: i64) -> Result<Self, Error> {
+        let (date, time) = from_pg_timestamp::<Y>(value)?;
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+        })
+    }
+
+    fn from_pg_text(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+impl<Y: YearDigits> PgBinary for PreciseShiftedDateTime<Y> {
+    const SIZE: usize = 8;
+
+    fn to_pg_int(&self) -> Result<i64, Error> {
+        let utc = self.to_timeshift(Timeshift::Utc)?;
+        pg_timestamp(
+            LocalDate::new(utc.year, utc.month, utc.day),
+            PreciseLocalTime::new(utc.hour, utc.minute, utc.second, utc.nanosecond),
+        )
+    }
+
+    fn from_pg_int(value: i64) -> Result<Self, Error> {
+        let (date, time) = from_pg_timestamp::<Y>(value)?;
+        Ok(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+            timeshift: Timeshift::Utc,
+        })
+    }
+
+    /// PostgreSQL leaves out the minutes of the offset when they are zero
+    fn from_pg_text(s: &str) -> Result<Self, Error> {
+        let bytes = s.as_bytes();
+        let date_time: Self = match bytes.len().checked_sub(3).map(|i| bytes[i]) {
+            Some(b'+' | b'-') => format!("{}:00", s).parse()?,
+            _ => s.parse()?,
+        };
+        date_time.to_timeshift(Timeshift::Utc)
+    }
+}
+
+/// `ToSql` and `FromSql` of the `postgres` crate, in the binary format
+#[cfg(feature = "postgres")]
+macro_rules! impl_postgres {
+    ($type:ident $(<$y:ident>)?, $pg_type:ident) => {
+        impl$(<$y: YearDigits + core::fmt::Debug>)? postgres_types::ToSql for $type$(<$y>)? {
+            fn to_sql(
+                &self,
+                _ty: &postgres_types::Type,
+                out: &mut bytes::BytesMut,
+            ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                out.extend_from_slice(&self.to_pg_bytes()?[8 - Self::SIZE..]);
+                Ok(postgres_types::IsNull::No)
+            }
+
+            postgres_types::accepts!($pg_type);
+
+            postgres_types::to_sql_checked!();
+        }
+
+        impl<'a, $($y: YearDigits)?> postgres_types::FromSql<'a> for $type$(<$y>)? {
+            fn from_sql(
+                _ty: &postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                Ok(Self::decode_pg(raw)?)
+            }
+
+            postgres_types::accepts!($pg_type);
+        }
+    };
+}
+
+#[cfg(feature = "postgres")]
+impl_postgres!(LocalDate<Y>, DATE);
+#[cfg(feature = "postgres")]
+impl_postgres!(PreciseLocalTime, TIME);
+#[cfg(feature = "postgres")]
+impl_postgres!(PreciseLocalDateTime<Y>, TIMESTAMP);
+#[cfg(feature = "postgres")]
+impl_postgres!(PreciseShiftedDateTime<Y>, TIMESTAMPTZ);
+
+/// `Type`, `Encode` and `Decode` of `sqlx` for PostgreSQL, in the binary format
+#[cfg(feature = "sqlx-postgres")]
+macro_rules! impl_sqlx_postgres {
+    ($type:ident $(<$y:ident>)?, $oid:literal) => {
+        impl$(<$y>)? sqlx::Type<sqlx::Postgres> for $type$(<$y>)? {
+            fn type_info() -> sqlx::postgres::PgTypeInfo {
+                sqlx::postgres::PgTypeInfo::with_oid(sqlx::postgres::types::Oid($oid))
+            }
+        }
+
+        impl$(<$y: YearDigits>)? sqlx::Encode<'_, sqlx::Postgres> for $type$(<$y>)? {
+            fn encode_by_ref(
+                &self,
+                buf: &mut sqlx::postgres::PgArgumentBuffer,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                self.encode_pg(buf)?;
+                Ok(sqlx::encode::IsNull::No)
+            }
+
+            fn size_hint(&self) -> usize {
+                Self::SIZE
+            }
+        }
+
+        impl<'r, $($y: YearDigits)?> sqlx::Decode<'r, sqlx::Postgres> for $type$(<$y>)? {
+            fn decode(
+                value: sqlx::postgres::PgValueRef<'r>,
+            ) -> Result<Self, sqlx::error::BoxDynError> {
+                Ok(match value.format() {
+                    sqlx::postgres::PgValueFormat::Binary => Self::decode_pg(value.as_bytes()?)?,
+                    sqlx::postgres::PgValueFormat::Text => Self::from_pg_text(value.as_str()?)?,
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_postgres!(LocalDate<Y>, 1082);
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_postgres!(PreciseLocalTime, 1083);
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_postgres!(PreciseLocalDateTime<Y>, 1114);
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_postgres!(PreciseShiftedDateTime<Y>, 1184);
+
+/// `ToSql` and `FromSql` of `rusqlite`, as RFC 3339 text
+#[cfg(feature = "rusqlite")]
+macro_rules! impl_rusqlite {
+    ($type:ident $(<$y:ident>)?) => {
+        impl$(<$y>)? rusqlite::ToSql for $type$(<$y>)?
+        $(where Year<$y>: core::fmt::Display)?
+        {
+            fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                Ok(self.to_string().into())
+            }
+        }
+
+        impl$(<$y: YearDigits>)? rusqlite::types::FromSql for $type$(<$y>)? {
+            fn column_result(
+                value: rusqlite::types::ValueRef<'_>,
+            ) -> rusqlite::types::FromSqlResult<Self> {
+                value
+                    .as_str()?
+                    .parse()
+                    .map_err(|e| rusqlite::types::FromSqlError::Other(Box::new(e)))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rusqlite")]
+impl_rusqlite!(LocalDate<Y>);
+#[cfg(feature = "rusqlite")]
+impl_rusqlite!(PreciseLocalTime);
+#[cfg(feature = "rusqlite")]
+impl_rusqlite!(PreciseLocalDateTime<Y>);
+#[cfg(feature = "rusqlite")]
+impl_rusqlite!(PreciseShiftedDateTime<Y>);
+
+/// `Type`, `Encode` and `Decode` of `sqlx` for SQLite, as RFC 3339 text
+#[cfg(feature = "sqlx-sqlite")]
+macro_rules! impl_sqlx_sqlite {
+    ($type:ident $(<$y:ident>)?) => {
+        impl$(<$y>)? sqlx::Type<sqlx::Sqlite> for $type$(<$y>)? {
+            fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+                <str as sqlx::Type<sqlx::Sqlite>>::type_info()
+            }
+
+            fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+                <str as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            }
+        }
+
+        impl$(<$y>)? sqlx::Encode<'_, sqlx::Sqlite> for $type$(<$y>)?
+        $(where Year<$y>: core::fmt::Display)?
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'_>>,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                sqlx::Encode::<sqlx::Sqlite>::encode(self.to_string(), buf)
+            }
+        }
+
+        impl<'r, $($y: YearDigits)?> sqlx::Decode<'r, sqlx::Sqlite> for $type$(<$y>)? {
+            fn decode(
+                value: sqlx::sqlite::SqliteValueRef<'r>,
+            ) -> Result<Self, sqlx::error::BoxDynError> {
+                let s = <&str as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+                Ok(s.parse()?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_sqlite!(LocalDate<Y>);
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_sqlite!(PreciseLocalTime);
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_sqlite!(PreciseLocalDateTime<Y>);
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_sqlite!(PreciseShiftedDateTime<Y>);
+
+/// The MySQL value types of `sqlx` are not public, so these go through the
+/// chrono types, which `sqlx` sends in the binary format
+#[cfg(feature = "sqlx-mysql")]
+mod mysql {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use sqlx::{error::BoxDynError, mysql::MySqlTypeInfo, Decode, Encode, MySql, Type};
+
+    use super::*;
+
+    fn to_naive_date<Y>(date: LocalDate<Y>) -> Result<NaiveDate, Error> {
+        NaiveDate::from_ymd_opt(date.year.value(), date.month.into(), date.day.into())
+            .ok_or(Error::Range)
+    }
+
+    fn to_naive_time(time: PreciseLocalTime) -> Result<NaiveTime, Error> {
+        match (u8::from(time.hour), u8::from(time.second)) {
+            (24, _) => Err(Error::EndOfDay),
+            (_, 60) => Err(Error::LeapSecond),
+            (hour, second) => NaiveTime::from_hms_nano_opt(
+                hour.into(),
+                u8::from(time.minute).into(),
+                second.into(),
+                time.nanosecond.into(),
+            )
+            .ok_or(Error::Range),
+        }
+    }
+
+    fn to_naive_date_time<Y: YearDigits>(
+        date_time: PreciseLocalDateTime<Y>,
+    ) -> Result<NaiveDateTime, Error> {
+        let date_time = date_time.normalize_end_of_day()?;
+        Ok(to_naive_date(LocalDate::new(
+            date_time.year,
+            date_time.month,
+            date_time.day,
+        ))?
+        .and_time(to_naive_time(PreciseLocalTime::new(
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+            date_time.nanosecond,
+        ))?))
+    }
+
+    macro_rules! impl_sqlx_mysql {
+        ($type:ident $(<$y:ident>)?, $chrono:ty, $to:expr) => {
+            impl$(<$y>)? Type<MySql> for $type$(<$y>)? {
+                fn type_info() -> MySqlTypeInfo {
+                    <$chrono as Type<MySql>>::type_info()
+                }
+
+                fn compatible(ty: &MySqlTypeInfo) -> bool {
+                    <$chrono as Type<MySql>>::compatible(ty)
+                }
+            }
+
+            impl$(<$y: YearDigits>)? Encode<'_, MySql> for $type$(<$y>)? {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut Vec<u8>,
+                ) -> Result<sqlx::encode::IsNull, BoxDynError> {
+                    let to: fn(Self) -> Result<$chrono, Error> = $to;
+                    Encode::<MySql>::encode(to(*self)?, buf)
+                }
+            }
+
+            impl<'r, $($y: YearDigits)?> Decode<'r, MySql> for $type$(<$y>)? {
+                fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+                    let value = <$chrono as Decode<MySql>>::decode(value)?;
+                    Ok(value.try_into()?)
+                }
+            }
+        };
+    }
+
+    impl_sqlx_mysql!(LocalDate<Y>, NaiveDate, to_naive_date);
+    impl_sqlx_mysql!(PreciseLocalTime, NaiveTime, to_naive_time);
+    impl_sqlx_mysql!(PreciseLocalDateTime<Y>, NaiveDateTime, to_naive_date_time);
+    // Like chrono's `DateTime<Utc>`, this assumes the connection time zone is UTC
+    impl_sqlx_mysql!(
+        PreciseShiftedDateTime<Y>,
+        DateTime<Utc>,
+        |date_time: PreciseShiftedDateTime<Y>| {
+            let utc = date_time.to_timeshift(Timeshift::Utc)?;
+            Ok(to_naive_date_time(PreciseLocalDateTime {
+                year: utc.year,
+                month: utc.month,
+                day: utc.day,
+                hour: utc.hour,
+                minute: utc.minute,
+                second: utc.second,
+                nanosecond: utc.nanosecond,
+            })?
+            .and_utc())
+        }
+    );
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "postgres",
+        feature = "sqlx-postgres",
+        feature = "sqlx-mysql",
+        feature = "sqlite-tests"
+    )
+))]
+mod tests {
+    use crate::combined::{
+        LocalDate, PreciseLocalDateTime, PreciseLocalTime, PreciseShiftedDateTime,
+    };
+    #[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+    use crate::components::{Error, ExtendedYear};
+
+    #[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+    fn round_trip<T>(value: &str, hex: &str)
+    where
+        T: super::PgBinary + core::str::FromStr<Err = Error> + PartialEq + core::fmt::Debug,
+    {
+        let expected: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        let value: T = value.parse().unwrap();
+        let mut buf = Vec::new();
+        value.encode_pg(&mut buf).unwrap();
+        assert_eq!(buf, expected, "{:?}", value);
+        assert_eq!(T::decode_pg(&expected).unwrap(), value);
+    }
+
+    #[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+    #[test]
+    fn test_postgres_binary() {
+        let datafile = include_str!("../data/postgres-binary.txt");
+        for line in datafile.lines() {
+            let mut parts = line.split(' ');
+            let (ty, value, hex) = (
+                parts.next().unwrap(),
+                parts.next().unwrap(),
+                parts.next().unwrap(),
+            );
+            match ty {
+                "date" => round_trip::<LocalDate>(value, hex),
+                "time" => round_trip::<PreciseLocalTime>(value, hex),
+                "timestamp" => round_trip::<PreciseLocalDateTime>(value, hex),
+                "timestamptz" => round_trip::<PreciseShiftedDateTime>(value, hex),
+                _ => panic!("unknown type {}", ty),
+            }
+        }
+    }
+
+    #[cfg(any(feature = "postgres", feature = "sqlx-postgres"))]
+    #[test]
+    fn test_postgres_errors() {
+        use super::PgBinary;
+
+        let shifted: PreciseShiftedDateTime =
+            "2023-09-17T11:08:58.763072999+02:00".parse().unwrap();
+        let utc: PreciseShiftedDateTime = "2023-09-17T09:08:58.763072Z".parse().unwrap();
+        assert_eq!(shifted.to_pg_int().unwrap(), utc.to_pg_int().unwrap());
+
+        let leap: PreciseShiftedDateTime = "2016-12-31T23:59:60Z".parse().unwrap();
+        assert!(matches!(leap.to_pg_int(), Err(Error::LeapSecond)));
+        let far: LocalDate<ExtendedYear<9>> = "+999999999-01-01".parse().unwrap();
+        assert!(matches!(far.to_pg_bytes(), Err(Error::Range)));
+
+        assert!(matches!(
+            LocalDate::<crate::SimpleYear>::decode_pg(&i32::MAX.to_be_bytes()),
+            Err(Error::Range)
+        ));
+        assert!(matches!(
+            PreciseShiftedDateTime::<crate::SimpleYear>::decode_pg(&i64::MIN.to_be_bytes()),
+            Err(Error::Range)
+        ));
+        assert!(matches!(
+            PreciseLocalTime::decode_pg(&[0; 4]),
+            Err(Error::Parse)
+        ));
+        assert!(matches!(
+            PreciseLocalTime::decode_pg(&(86_400_000_001i64).to_be_bytes()),
+            Err(Error::Range)
+        ));
+
+        assert_eq!(
+            PreciseShiftedDateTime::from_pg_text("2023-09-17 09:08:58.763072+00").unwrap(),
+            utc
+        );
+        assert_eq!(
+            PreciseShiftedDateTime::from_pg_text("2023-09-17 11:08:58.763072+02").unwrap(),
+            utc
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_types() {
+        use postgres_types::{FromSql, ToSql, Type};
+
+        let date: LocalDate = "2023-09-17".parse().unwrap();
+        let mut out = bytes::BytesMut::new();
+        date.to_sql_checked(&Type::DATE, &mut out).unwrap();
+        assert_eq!(&out[..], &[0x00, 0x00, 0x21, 0xd4]);
+        assert_eq!(LocalDate::from_sql(&Type::DATE, &out).unwrap(), date);
+        assert!(date.to_sql_checked(&Type::TIMESTAMP, &mut out).is_err());
+
+        let shifted: PreciseShiftedDateTime = "2023-09-17T09:08:58.763072Z".parse().unwrap();
+        let mut out = bytes::BytesMut::new();
+        shifted
+            .to_sql_checked(&Type::TIMESTAMPTZ, &mut out)
+            .unwrap();
+        assert_eq!(&out[..], &0x0002a88925092340i64.to_be_bytes());
+        assert_eq!(
+            PreciseShiftedDateTime::from_sql(&Type::TIMESTAMPTZ, &out).unwrap(),
+            shifted
+        );
+        assert!(<PreciseLocalTime as FromSql>::accepts(&Type::TIME));
+        assert!(<PreciseLocalDateTime as ToSql>::accepts(&Type::TIMESTAMP));
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn test_sqlx_postgres() {
+        use sqlx::{
+            encode::IsNull,
+            postgres::{types::Oid, PgArgumentBuffer},
+            Encode, Postgres, Type,
+        };
+
+        let date: LocalDate = "2023-09-17".parse().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(
+            Encode::<Postgres>::encode_by_ref(&date, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&buf[..], &[0x00, 0x00, 0x21, 0xd4]);
+        assert_eq!(
+            <PreciseShiftedDateTime as Type<Postgres>>::type_info().oid(),
+            Some(Oid(1184))
+        );
+    }
+
+    #[cfg(feature = "sqlite-tests")]
+    #[test]
+    fn test_rusqlite() {
+        let connection = rusqlite::Connection::open_in_memory().unwrap();
+        connection
+            .execute(
+                "CREATE TABLE event (date DATE, time TIME, local TIMESTAMP, shifted TEXT)",
+                (),
+            )
+            .unwrap();
+
+        let date: LocalDate = "2023-09-17".parse().unwrap();
+        let time: PreciseLocalTime = "24:00:00".parse().unwrap();
+        let local: PreciseLocalDateTime = "2016-12-31T23:59:60.5".parse().unwrap();
+        let shifted: PreciseShiftedDateTime =
+            "2023-09-17T09:08:58.763072999-05:30".parse().unwrap();
+        connection
+            .execute(
+                "INSERT INTO event VALUES (?1, ?2, ?3, ?4)",
+                (date, time, local, shifted),
+            )
+            .unwrap();
+
+        let row: (
+            LocalDate,
+            PreciseLocalTime,
+            PreciseLocalDateTime,
+            PreciseShiftedDateTime,
+        ) = connection
+            .query_row("SELECT * FROM event", (), |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })
+            .unwrap();
+        assert_eq!(row, (date, time, local, shifted));
+
+        let text: String = connection
+            .query_row("SELECT shifted FROM event", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(text, "2023-09-17T09:08:58.763072999-05:30");
+        let invalid =
+            connection.query_row("SELECT 'yesterday'", (), |row| row.get::<_, LocalDate>(0));
+        assert!(invalid.is_err());
+    }
+
+    #[cfg(feature = "sqlite-tests")]
+    #[test]
+    fn test_sqlx_sqlite() {
+        use sqlx::{Connection, SqliteConnection};
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE event (date DATE, time TIME, local TIMESTAMP, shifted TEXT)")
+                .execute(&mut connection)
+                .await
+                .unwrap();
+
+            let date: LocalDate = "2023-09-17".parse().unwrap();
+            let time: PreciseLocalTime = "09:08:58.763072".parse().unwrap();
+            let local: PreciseLocalDateTime = "2023-09-17T24:00:00".parse().unwrap();
+            let shifted: PreciseShiftedDateTime = "2023-09-17T09:08:58Z".parse().unwrap();
+            sqlx::query("INSERT INTO event VALUES (?, ?, ?, ?)")
+                .bind(date)
+                .bind(time)
+                .bind(local)
+                .bind(shifted)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+
+            let row: (
+                LocalDate,
+                PreciseLocalTime,
+                PreciseLocalDateTime,
+                PreciseShiftedDateTime,
+            ) = sqlx::query_as("SELECT * FROM event")
+                .fetch_one(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(row, (date, time, local, shifted));
+
+            let text: String = sqlx::query_scalar("SELECT local FROM event")
+                .fetch_one(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(text, "2023-09-17T24:00:00.0");
+        });
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    #[test]
+    fn test_sqlx_mysql() {
+        use sqlx::{encode::IsNull, Encode, MySql};
+
+        let mut buf = Vec::new();
+        let date: LocalDate = "2023-09-17".parse().unwrap();
+        assert!(matches!(
+            Encode::<MySql>::encode_by_ref(&date, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(buf, [4, 0xe7, 0x07, 9, 17]);
+
+        let mut buf = Vec::new();
+        let shifted: PreciseShiftedDateTime = "2023-09-17T11:08:58.5+02:00".parse().unwrap();
+        assert!(matches!(
+            Encode::<MySql>::encode_by_ref(&shifted, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(
+            buf,
+            [11, 0xe7, 0x07, 9, 17, 9, 8, 58, 0x20, 0xa1, 0x07, 0x00]
+        );
+
+        let mut buf = Vec::new();
+        let local: PreciseLocalDateTime = "2023-09-17T09:08:58".parse().unwrap();
+        assert!(matches!(
+            Encode::<MySql>::encode_by_ref(&local, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(buf, [7, 0xe7, 0x07, 9, 17, 9, 8, 58]);
+
+        let time: PreciseLocalTime = "24:00:00".parse().unwrap();
+        assert!(Encode::<MySql>::encode_by_ref(&time, &mut Vec::new()).is_err());
+    }
+}
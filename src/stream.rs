@@ -0,0 +1,313 @@
+//! Parse a date-time on every line of a reader
+//!
+//! [`Lines`] reads from a [`BufRead`] one line at a time into a buffer that is
+//! reused for every line, and parses each line as a whole with the profile of
+//! a [`Builder`]. A trailing `\n` or `\r\n` is not part of the date-time.
+//!
+//! By default the iterator stops after the first error, which is returned with
+//! its line number. With [`Lines::continue_on_error`] it goes on to the next
+//! line instead, and [`Lines::statistics`] tells how many lines failed.
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::{stream::Lines, Builder};
+//! let input = "2023-09-17T09:08:58Z\nnot a date\n2023-09-17T11:08:58.5+02:00\n";
+//! let mut lines = Lines::new(input.as_bytes(), Builder::new_rfc3339());
+//! lines.continue_on_error(true);
+//! let parsed: Vec<_> = lines.by_ref().collect();
+//! assert!(parsed[0].is_ok());
+//! assert!(matches!(parsed[1], Err((2, _))));
+//! assert_eq!(lines.statistics().errors, 1);
+//! ```
+
+use core::fmt;
+use std::io::{self, BufRead};
+
+use crate::{
+    combined::PreciseShiftedDateTime,
+    components,
+    parse::{BuildError, Builder, Parser},
+    parse_utils::ParseError,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The line does not follow the syntax of the profile, starting at this byte
+    Syntax {
+        position: usize,
+    },
+    /// The line is not a date-time of the profile
+    DateTime(components::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read line: {}", e),
+            Error::Syntax { position } => write!(f, "invalid date-time at byte {}", position),
+            Error::DateTime(e) => write!(f, "invalid date-time: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Syntax { .. } => None,
+            Error::DateTime(e) => Some(e),
+        }
+    }
+}
+
+impl Error {
+    /// The error of a parser that stopped at `rest` of `line`
+    fn parse(line: &[u8], error: ParseError<'_>) -> Self {
+        match error {
+            ParseError::Fail(rest) => Error::Syntax {
+                position: line.len() - rest.len(),
+            },
+            ParseError::UnexpectedEof { .. } => Error::Syntax {
+                position: line.len(),
+            },
+            ParseError::RangeError => Error::DateTime(components::Error::Range),
+            _ => Error::DateTime(components::Error::Parse),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::DateTime(value)
+    }
+}
+
+impl<Y> From<BuildError<Y>> for Error {
+    fn from(value: BuildError<Y>) -> Self {
+        match value {
+            BuildError::Invalid(e) => Error::DateTime(e),
+            _ => Error::DateTime(components::Error::Parse),
+        }
+    }
+}
+
+/// Counts of the lines read so far
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Statistics {
+    pub lines: u64,
+    pub parsed: u64,
+    pub errors: u64,
+    /// The first date-time in time, not in the input
+    pub earliest: Option<PreciseShiftedDateTime>,
+    /// The last date-time in time, not in the input
+    pub latest: Option<PreciseShiftedDateTime>,
+}
+
+impl Statistics {
+    fn record(&mut self, date_time: PreciseShiftedDateTime) {
+        let instant = |dt: &PreciseShiftedDateTime| (dt.unix_timestamp(), dt.nanosecond);
+        self.parsed += 1;
+        if self
+            .earliest
+            .is_none_or(|e| instant(&date_time) < instant(&e))
+        {
+            self.earliest = Some(date_time);
+        }
+        if self
+            .latest
+            .is_none_or(|l| instant(&date_time) > instant(&l))
+        {
+            self.latest = Some(date_time);
+        }
+    }
+}
+
+/// Iterator over the date-times on the lines of a reader, see the [module documentation](self)
+pub struct Lines<R> {
+    reader: R,
+    parser: Parser,
+    buffer: Vec<u8>,
+    line_no: usize,
+    continue_on_error: bool,
+    done: bool,
+    statistics: Statistics,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R, builder: Builder) -> Self {
+        Self {
+            reader,
+            parser: builder.into_parser(),
+            buffer: Vec::new(),
+            line_no: 0,
+            continue_on_error: false,
+            done: false,
+            statistics: Statistics::default(),
+        }
+    }
+
+    /// Go on with the next line after a line that is not a date-time
+    ///
+    /// Errors reading from the reader always end the iterator.
+    pub fn continue_on_error(&mut self, continue_on_error: bool) -> &mut Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    pub fn statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn parse_line(&mut self) -> Result<PreciseShiftedDateTime, Error> {
+        let line = self.buffer.strip_suffix(b"\n").unwrap_or(&self.buffer);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        self.parser.reset();
+        let rest = self
+            .parser
+            .parse_precise_shifted_date_time(line)
+            .map_err(|e| Error::parse(line, e))?;
+        if !rest.is_empty() {
+            return Err(Error::parse(line, ParseError::Fail(rest)));
+        }
+        Ok(self.parser.take_precise_shifted_date_time()?)
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<PreciseShiftedDateTime, (usize, Error)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.buffer.clear();
+        self.line_no += 1;
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.done = true;
+                return Some(Err((self.line_no, e.into())));
+            }
+        }
+        self.statistics.lines += 1;
+        match self.parse_line() {
+            Ok(date_time) => {
+                self.statistics.record(date_time);
+                Some(Ok(date_time))
+            }
+            Err(e) => {
+                self.statistics.errors += 1;
+                self.done = !self.continue_on_error;
+                Some(Err((self.line_no, e)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::{Error, Lines};
+    use crate::{components, Builder};
+
+    const INPUT: &str = "2023-09-17T09:08:58Z\r\n2023-09-17 09:08:58Z\n\n2023-09-17T11:08:59.5+02:00\n2023-09-17T09:08:58\n2023-09-17T07:08:57-02:00";
+
+    #[test]
+    fn test_lines() {
+        let mut lines = Lines::new(INPUT.as_bytes(), Builder::new_rfc3339());
+        lines.continue_on_error(true);
+        let results: Vec<_> = lines.by_ref().collect();
+        assert_eq!(results.len(), 6);
+        assert_eq!(
+            results[0].as_ref().unwrap().to_string(),
+            "2023-09-17T09:08:58.0Z"
+        );
+        assert!(results[1].is_ok());
+        assert!(matches!(
+            results[2],
+            Err((3, Error::Syntax { position: 0 }))
+        ));
+        assert!(results[3].is_ok());
+        assert!(matches!(
+            results[4],
+            Err((5, Error::Syntax { position: 19 }))
+        ));
+        assert!(results[5].is_ok());
+
+        let statistics = lines.statistics();
+        assert_eq!(statistics.lines, 6);
+        assert_eq!(statistics.parsed, 4);
+        assert_eq!(statistics.errors, 2);
+        assert_eq!(
+            statistics.earliest.unwrap().to_string(),
+            "2023-09-17T07:08:57.0-02:00"
+        );
+        assert_eq!(
+            statistics.latest.unwrap().to_string(),
+            "2023-09-17T11:08:59.5+02:00"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "2023-09-17T09:08:58Zx\n2023-13-17T09:08:58Z\n2023-09-17X09:08:58Z";
+        let mut lines = Lines::new(input.as_bytes(), Builder::new_rfc3339());
+        lines.continue_on_error(true);
+        let results: Vec<_> = lines.collect();
+        assert!(matches!(
+            results[0],
+            Err((1, Error::Syntax { position: 20 }))
+        ));
+        assert!(matches!(
+            results[1],
+            Err((2, Error::DateTime(components::Error::Range)))
+        ));
+        assert!(matches!(
+            results[2],
+            Err((3, Error::Syntax { position: 10 }))
+        ));
+    }
+
+    #[test]
+    fn test_stop_on_error() {
+        let mut lines = Lines::new(INPUT.as_bytes(), Builder::new_strict_rfc3339());
+        assert!(lines.next().unwrap().is_ok());
+        assert!(matches!(lines.next(), Some(Err((2, _)))));
+        assert!(lines.next().is_none());
+        assert_eq!(lines.statistics().lines, 2);
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn test_io_error() {
+        let reader = BufReader::new((&b"2023-09-17T09:08:58Z\n"[..]).chain(Failing));
+        let mut lines = Lines::new(reader, Builder::new_rfc3339());
+        lines.continue_on_error(true);
+        assert!(lines.next().unwrap().is_ok());
+        assert!(matches!(lines.next(), Some(Err((2, Error::Io(_))))));
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,635 @@
+//! Time zones from the IANA time zone database
+//!
+//! Reads the compiled TZif files of RFC 8536, versions 1 to 4, as found in
+//! `/usr/share/zoneinfo`.
+//!
+//! Local time types with an offset that is not a whole number of minutes,
+//! like the local mean time used before standard time zones, can't be
+//! expressed as a [`Timeshift`] and fail with [`components::Error::Range`].
+//!
+//! ## Example
+//! ```rust,no_run
+//! # use datetimeparse::tz::{LocalResult, TimeZone};
+//! # use datetimeparse::LocalDateTime;
+//! let amsterdam = TimeZone::from_zoneinfo("Europe/Amsterdam").unwrap();
+//! let local = LocalDateTime::try_from((2023, 3, 26, 2, 30, 0)).unwrap();
+//! assert!(matches!(amsterdam.resolve(&local), Ok(LocalResult::Gap { .. })));
+//! ```
+
+use core::{fmt, str};
+use std::{fs, io, path::Path};
+
+use crate::{
+    calendar,
+    combined::{LocalDateTime, PreciseLocalDateTime, PreciseShiftedDateTime},
+    components::{self, Day, Hour, Minute, Month, Second, Timeshift, Year, YearDigits},
+    posix_tz::PosixTimeZone,
+};
+
+/// The directory with the system copy of the time zone database
+pub const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The data is not a valid TZif file
+    InvalidTzif,
+    /// The time zone name is empty, absolute, or contains `.` or `..`
+    InvalidName,
+    /// The TZif file has leap second records, like the `right/` zones
+    LeapSeconds,
+    Component(components::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::InvalidTzif => write!(f, "invalid TZif data"),
+            Error::InvalidName => write!(f, "invalid time zone name"),
+            Error::LeapSeconds => write!(f, "TZif leap second records are not supported"),
+            Error::Component(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Component(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::Component(value)
+    }
+}
+
+/// A local time type of a time zone, like CET or CEST
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    /// Seconds east of UTC
+    pub utc_offset: i32,
+    pub is_dst: bool,
+    pub abbreviation: String,
+}
+
+impl LocalTimeType {
+    pub fn timeshift(&self) -> Result<Timeshift, components::Error> {
+        Timeshift::from_seconds_from_east(self.utc_offset)
+    }
+}
+
+/// How a local date-time maps to the time line of a time zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalResult {
+    /// The local date-time happens once
+    Single(Timeshift),
+    /// The local date-time happens twice, as the clock was turned back
+    Fold {
+        earlier: Timeshift,
+        later: Timeshift,
+    },
+    /// The local date-time is skipped, as the clock was turned forward
+    Gap { before: Timeshift, after: Timeshift },
+}
+
+impl LocalResult {
+    /// The time shift when there is exactly one
+    pub fn single(self) -> Option<Timeshift> {
+        match self {
+            LocalResult::Single(timeshift) => Some(timeshift),
+            _ => None,
+        }
+    }
+}
+
+/// A time zone read from a TZif file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    /// Unix timestamps at which the local time type changes, ascending
+    transitions: Vec<i64>,
+    /// Index into `types` for each transition
+    transition_types: Vec<u8>,
+    /// The first type is used before the first transition
+    types: Vec<LocalTimeType>,
+    /// POSIX TZ string for times after the last transition
+    footer: Option<PosixTimeZone>,
+}
+
+/// Reads big-endian integers from the start of a TZif file
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.data.len() < n {
+            return Err(Error::InvalidTzif);
+        }
+        let (taken, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<usize, Error> {
+        let bytes = self.take(4)?.try_into().expect("took 4 bytes");
+        Ok(u32::from_be_bytes(bytes) as usize)
+    }
+
+    fn time(&mut self, size: usize) -> Result<i64, Error> {
+        let bytes = self.take(size)?;
+        Ok(match size {
+            4 => i32::from_be_bytes(bytes.try_into().expect("took 4 bytes")).into(),
+            _ => i64::from_be_bytes(bytes.try_into().expect("took 8 bytes")),
+        })
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        if reader.take(4)? != b"TZif" {
+            return Err(Error::InvalidTzif);
+        }
+        let version = reader.take(1)?[0];
+        if !matches!(version, 0 | b'2' | b'3' | b'4') {
+            return Err(Error::InvalidTzif);
+        }
+        reader.take(15)?;
+        Ok(Self {
+            version,
+            isutcnt: reader.u32()?,
+            isstdcnt: reader.u32()?,
+            leapcnt: reader.u32()?,
+            timecnt: reader.u32()?,
+            typecnt: reader.u32()?,
+            charcnt: reader.u32()?,
+        })
+    }
+
+    /// Length of the data block with times of `time_size` bytes
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+impl TimeZone {
+    /// Read a time zone from the contents of a TZif file
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader { data };
+        let mut header = Header::read(&mut reader)?;
+        let mut time_size = 4;
+        if header.version != 0 {
+            // Skip the version 1 data, the version 2 data has 64-bit times
+            reader.take(header.data_len(4))?;
+            header = Header::read(&mut reader)?;
+            time_size = 8;
+        }
+        if header.typecnt == 0
+            || header.charcnt == 0
+            || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+            || (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+        {
+            return Err(Error::InvalidTzif);
+        }
+        if header.leapcnt != 0 {
+            return Err(Error::LeapSeconds);
+        }
+
+        let transitions = (0..header.timecnt)
+            .map(|_| reader.time(time_size))
+            .collect::<Result<Vec<_>, _>>()?;
+        if transitions.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Error::InvalidTzif);
+        }
+        let transition_types = reader.take(header.timecnt)?.to_vec();
+        if transition_types
+            .iter()
+            .any(|&t| t as usize >= header.typecnt)
+        {
+            return Err(Error::InvalidTzif);
+        }
+        let mut raw_types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let utc_offset = reader.time(4)? as i32;
+            let is_dst = reader.take(1)?[0];
+            let index = reader.take(1)?[0] as usize;
+            if utc_offset == i32::MIN || is_dst > 1 || index >= header.charcnt {
+                return Err(Error::InvalidTzif);
+            }
+            raw_types.push((utc_offset, is_dst == 1, index));
+        }
+        let chars = reader.take(header.charcnt)?;
+        let types = raw_types
+            .into_iter()
+            .map(|(utc_offset, is_dst, index)| {
+                let abbreviation = chars[index..]
+                    .split(|&c| c == 0)
+                    .next()
+                    .filter(|_| chars[index..].contains(&0))
+                    .ok_or(Error::InvalidTzif)?;
+                Ok(LocalTimeType {
+                    utc_offset,
+                    is_dst,
+                    abbreviation: String::from_utf8(abbreviation.to_vec())
+                        .map_err(|_| Error::InvalidTzif)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        reader.take(header.isstdcnt + header.isutcnt)?;
+
+        let footer = if header.version != 0 {
+            let footer = reader.data;
+            let footer = footer
+                .strip_prefix(b"\n")
+                .and_then(|footer| footer.strip_suffix(b"\n"))
+                .filter(|footer| !footer.contains(&b'\n'))
+                .ok_or(Error::InvalidTzif)?;
+            match str::from_utf8(footer).map_err(|_| Error::InvalidTzif)? {
+                "" => None,
+                footer => Some(footer.parse().map_err(|_| Error::InvalidTzif)?),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            transitions,
+            transition_types,
+            types,
+            footer,
+        })
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(&fs::read(path)?)
+    }
+
+    /// Read a time zone like `Europe/Amsterdam` from a zoneinfo directory
+    pub fn from_dir(dir: impl AsRef<Path>, name: &str) -> Result<Self, Error> {
+        if name
+            .split('/')
+            .any(|part| matches!(part, "" | "." | "..") || part.contains('\\'))
+        {
+            return Err(Error::InvalidName);
+        }
+        Self::from_file(dir.as_ref().join(name))
+    }
+
+    /// Read a time zone like `Europe/Amsterdam` from [`ZONEINFO`]
+    pub fn from_zoneinfo(name: &str) -> Result<Self, Error> {
+        Self::from_dir(ZONEINFO, name)
+    }
+
+    /// The POSIX TZ string that the file gives for times after the last transition
+    pub fn footer(&self) -> Option<&PosixTimeZone> {
+        self.footer.as_ref()
+    }
+
+    /// The local time type at a number of seconds since 1970-01-01T00:00:00Z
+    ///
+    /// After the last transition the footer is used. Without a footer, like in
+    /// version 1 files, the local time type of the last transition stays in use.
+    pub fn local_time_type(&self, unix_timestamp: i64) -> &LocalTimeType {
+        let index = self.transitions.partition_point(|&t| t <= unix_timestamp);
+        match (index, &self.footer) {
+            (0, _) => &self.types[0],
+            (index, Some(footer)) if index == self.transitions.len() => {
+                footer.local_time_type(unix_timestamp)
+            }
+            _ => &self.types[self.transition_types[index - 1] as usize],
+        }
+    }
+
+    /// The same point in time in the local time of this time zone
+    pub fn to_local<Y: YearDigits>(
+        &self,
+        date_time: PreciseShiftedDateTime<Y>,
+    ) -> Result<PreciseShiftedDateTime<Y>, Error> {
+        let local_time_type = self.local_time_type(date_time.unix_timestamp());
+        Ok(date_time.to_timeshift(local_time_type.timeshift()?)?)
+    }
+
+    /// Find the time shift of a local date-time in this time zone
+    pub fn resolve<Y>(&self, date_time: &LocalDateTime<Y>) -> Result<LocalResult, Error> {
+        self.resolve_local(local_seconds(
+            &date_time.year,
+            date_time.month,
+            date_time.day,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+        ))
+    }
+
+    /// Find the time shift of a local date-time in this time zone
+    pub fn resolve_precise<Y>(
+        &self,
+        date_time: &PreciseLocalDateTime<Y>,
+    ) -> Result<LocalResult, Error> {
+        self.resolve_local(local_seconds(
+            &date_time.year,
+            date_time.month,
+            date_time.day,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+        ))
+    }
+
+    fn resolve_local(&self, local: i64) -> Result<LocalResult, Error> {
+        // Offsets are less than a day, so only transitions within a day of
+        // the local time can have a gap or fold around it
+        let start = self.transitions.partition_point(|&t| t < local - 86400);
+        let end = self.transitions.partition_point(|&t| t <= local + 86400);
+        let last = self.transitions.last().copied();
+        let footer_transitions = match (last, &self.footer) {
+            (Some(last), Some(footer)) if local + 86400 > last => {
+                Some(footer.transitions_around(local).filter(move |&t| t > last))
+            }
+            _ => None,
+        };
+        let transitions = self.transitions[start..end]
+            .iter()
+            .copied()
+            .chain(footer_transitions.into_iter().flatten());
+        Ok(resolve_local_seconds(local, transitions, |t| {
+            self.local_time_type(t).utc_offset
+        })?)
+    }
+}
+
+/// Find the time shifts of local seconds since 1970-01-01T00:00:00 from the
+/// transitions within a day of it and the offset at each Unix timestamp
+pub(crate) fn resolve_local_seconds(
+    local: i64,
+    transitions: impl IntoIterator<Item = i64>,
+    offset: impl Fn(i64) -> i32,
+) -> Result<LocalResult, components::Error> {
+    let timeshift = |offset: i64| Timeshift::from_seconds_from_east(offset as i32);
+    for transition in transitions {
+        if (transition - local).abs() > 86400 {
+            continue;
+        }
+        let before = i64::from(offset(transition - 1));
+        let after = i64::from(offset(transition));
+        if transition + before <= local && local < transition + after {
+            return Ok(LocalResult::Gap {
+                before: timeshift(before)?,
+                after: timeshift(after)?,
+            });
+        }
+        if transition + after <= local && local < transition + before {
+            return Ok(LocalResult::Fold {
+                earlier: timeshift(before)?,
+                later: timeshift(after)?,
+            });
+        }
+    }
+    let utc_offset = offset(local - i64::from(offset(local)));
+    if offset(local - i64::from(utc_offset)) != utc_offset {
+        // Only possible when transitions are closer together than a day
+        return Err(components::Error::Range);
+    }
+    timeshift(utc_offset.into()).map(LocalResult::Single)
+}
+
+/// Seconds since 1970-01-01T00:00:00 in local time, a leap second counting as the next second
+pub(crate) fn local_seconds<Y>(
+    year: &Year<Y>,
+    month: Month,
+    day: Day,
+    hour: Hour,
+    minute: Minute,
+    second: Second,
+) -> i64 {
+    let days = calendar::days_from_civil(year.value(), month.into(), day.into());
+    let hour: i64 = hour.into();
+    let minute: i64 = minute.into();
+    let second: i64 = second.into();
+    days * 86400 + hour * 3600 + minute * 60 + second
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, LocalResult, TimeZone};
+    use crate::{
+        combined::{LocalDateTime, PreciseShiftedDateTime},
+        components::{Hour, Minute, Timeshift},
+    };
+
+    const AMSTERDAM: &[u8] = include_bytes!("../data/zoneinfo/Europe/Amsterdam");
+
+    fn offset(hours: i32, minutes: u64) -> Timeshift {
+        Timeshift::offset(
+            hours >= 0,
+            Hour::new(hours.unsigned_abs().into()).unwrap(),
+            Minute::new(minutes).unwrap(),
+        )
+    }
+
+    fn local(s: &str) -> LocalDateTime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let zone = TimeZone::parse(AMSTERDAM).unwrap();
+        assert_eq!(
+            zone.footer().unwrap().to_string(),
+            "CET-1CEST,M3.5.0,M10.5.0/3"
+        );
+        assert_eq!(zone.local_time_type(1694941738).abbreviation, "CEST");
+        assert!(zone.local_time_type(1694941738).is_dst);
+        assert_eq!(zone.local_time_type(1700000000).abbreviation, "CET");
+
+        // Version 1 data only has 32-bit times and no footer
+        let mut v1 = AMSTERDAM.to_vec();
+        v1[4] = 0;
+        let zone_v1 = TimeZone::parse(&v1).unwrap();
+        assert_eq!(zone_v1.footer(), None);
+        assert_eq!(zone_v1.local_time_type(1694941738).abbreviation, "CEST");
+
+        for version in [b'3', b'4'] {
+            let mut data = AMSTERDAM.to_vec();
+            data[4] = version;
+            assert!(TimeZone::parse(&data).is_ok());
+        }
+
+        let utc = TimeZone::parse(include_bytes!("../data/zoneinfo/UTC")).unwrap();
+        assert_eq!(utc.footer().unwrap().to_string(), "UTC0");
+        assert_eq!(utc.local_time_type(0).abbreviation, "UTC");
+
+        assert!(matches!(TimeZone::parse(b"TZif2"), Err(Error::InvalidTzif)));
+        assert!(matches!(
+            TimeZone::parse(&AMSTERDAM[..AMSTERDAM.len() - 1]),
+            Err(Error::InvalidTzif)
+        ));
+        let mut wrong_magic = AMSTERDAM.to_vec();
+        wrong_magic[0] = b'X';
+        assert!(matches!(
+            TimeZone::parse(&wrong_magic),
+            Err(Error::InvalidTzif)
+        ));
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/data/zoneinfo");
+        let zone = TimeZone::from_dir(dir, "Europe/Amsterdam").unwrap();
+        assert_eq!(zone, TimeZone::parse(AMSTERDAM).unwrap());
+        assert!(TimeZone::from_dir(dir, "America/New_York").is_ok());
+        assert!(matches!(
+            TimeZone::from_dir(dir, "Europe/Nowhere"),
+            Err(Error::Io(_))
+        ));
+        for name in [
+            "",
+            "../zoneinfo/UTC",
+            "/etc/localtime",
+            "Europe/./Amsterdam",
+        ] {
+            assert!(
+                matches!(TimeZone::from_dir(dir, name), Err(Error::InvalidName)),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let zone = TimeZone::parse(AMSTERDAM).unwrap();
+        assert_eq!(
+            zone.resolve(&local("2023-09-17T11:08:58")).unwrap(),
+            LocalResult::Single(offset(2, 0))
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-01-17T11:08:58")).unwrap(),
+            LocalResult::Single(offset(1, 0))
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-03-26T02:30:00")).unwrap(),
+            LocalResult::Gap {
+                before: offset(1, 0),
+                after: offset(2, 0)
+            }
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-03-26T02:00:00")).unwrap(),
+            LocalResult::Gap {
+                before: offset(1, 0),
+                after: offset(2, 0)
+            }
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-03-26T03:00:00")).unwrap(),
+            LocalResult::Single(offset(2, 0))
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-10-29T02:30:00")).unwrap(),
+            LocalResult::Fold {
+                earlier: offset(2, 0),
+                later: offset(1, 0)
+            }
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-10-29T03:00:00")).unwrap(),
+            LocalResult::Single(offset(1, 0))
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-10-29T01:59:59")).unwrap(),
+            LocalResult::Single(offset(2, 0))
+        );
+        // The transitions in the file end in 2037, the footer has the rules after that
+        assert_eq!(
+            zone.resolve(&local("2050-03-27T02:30:00")).unwrap(),
+            LocalResult::Gap {
+                before: offset(1, 0),
+                after: offset(2, 0)
+            }
+        );
+        assert_eq!(
+            zone.resolve(&local("2050-10-30T02:30:00")).unwrap(),
+            LocalResult::Fold {
+                earlier: offset(2, 0),
+                later: offset(1, 0)
+            }
+        );
+        assert_eq!(zone.local_time_type(4118054400).abbreviation, "CEST");
+        // Local mean time of Amsterdam was +00:19:32
+        assert!(matches!(
+            zone.resolve(&local("1900-01-01T00:00:00")),
+            Err(Error::Component(_))
+        ));
+
+        // Lord Howe Island moves the clock by 30 minutes
+        let zone = TimeZone::parse(include_bytes!("../data/zoneinfo/Australia/Lord_Howe")).unwrap();
+        assert_eq!(
+            zone.resolve(&local("2023-04-02T01:45:00")).unwrap(),
+            LocalResult::Fold {
+                earlier: offset(11, 0),
+                later: offset(10, 30)
+            }
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-10-01T02:15:00")).unwrap(),
+            LocalResult::Gap {
+                before: offset(10, 30),
+                after: offset(11, 0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_local() {
+        let zone = TimeZone::parse(include_bytes!("../data/zoneinfo/America/New_York")).unwrap();
+        let utc: PreciseShiftedDateTime = "2023-11-05T05:30:00Z".parse().unwrap();
+        assert_eq!(
+            zone.to_local(utc).unwrap().to_string(),
+            "2023-11-05T01:30:00.0-04:00"
+        );
+        let utc: PreciseShiftedDateTime = "2023-11-05T06:30:00Z".parse().unwrap();
+        assert_eq!(
+            zone.to_local(utc).unwrap().to_string(),
+            "2023-11-05T01:30:00.0-05:00"
+        );
+        let shifted: PreciseShiftedDateTime = "2023-09-17T11:08:58.5+02:00".parse().unwrap();
+        assert_eq!(
+            zone.to_local(shifted).unwrap().to_string(),
+            "2023-09-17T05:08:58.5-04:00"
+        );
+        assert_eq!(
+            zone.resolve(&local("2023-11-05T01:30:00")).unwrap(),
+            LocalResult::Fold {
+                earlier: offset(-4, 0),
+                later: offset(-5, 0)
+            }
+        );
+    }
+}
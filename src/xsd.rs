@@ -0,0 +1,902 @@
+//! XML Schema date and time datatypes
+//!
+//! XML Schema differs from RFC 3339: the time zone is optional on every
+//! type, years can be negative or have more than four digits, and there are
+//! partial dates like `--09-17` for `xs:gMonthDay`. A time of `24:00:00` is
+//! the start of the next day.
+//!
+//! Years are numbered as in XSD 1.1 and ISO 8601, where year 0 is 1 BCE and
+//! -1 is 2 BCE. XSD 1.0 has no year 0 and calls 1 BCE -1, which
+//! [`Version::Xsd10`] converts when parsing and when formatting with
+//! `display_version`.
+//!
+//! Years are a plain `i32` rather than a [`Year`](crate::Year): XSD years
+//! have at least four digits but no fixed maximum, and never have a `+`
+//! sign, while an [`ExtendedYear`](crate::ExtendedYear) always has a sign and
+//! a fixed number of digits. Any year in the `i32` range can be parsed.
+//!
+//! ## Ordering
+//!
+//! Values are compared on the time line, as in XSD, so `10:00:00Z` equals
+//! `12:00:00+02:00`. A value without a time zone could be in any time zone
+//! from -14:00 to +14:00. It is only ordered against a value with a time zone
+//! when that is true for all of those time zones, so the types implement
+//! [`PartialOrd`] and not [`Ord`].
+//!
+//! ## Example
+//! ```rust
+//! # use datetimeparse::xsd::{DateTime, GMonthDay};
+//! let a: DateTime = "2023-09-17T09:08:58Z".parse().unwrap();
+//! let b: DateTime = "2023-09-17T11:08:58+02:00".parse().unwrap();
+//! assert_eq!(a, b);
+//! let local: DateTime = "2023-09-17T09:08:58".parse().unwrap();
+//! assert_eq!(a.partial_cmp(&local), None);
+//! let birthday: GMonthDay = "--02-29".parse().unwrap();
+//! assert_eq!(birthday.to_string(), "--02-29");
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Deref, DerefMut},
+    str,
+};
+
+use crate::{
+    calendar,
+    combined::end_of_day,
+    components::{self, Day, Hour, Minute, Month, Nanosecond, Second, Timeshift},
+    parse_utils::{self, SyntaxError},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The value does not follow the XSD syntax, starting at this byte
+    Syntax {
+        position: usize,
+    },
+    DateTime(components::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Syntax { position } => write!(f, "invalid value at byte {}", position),
+            Error::DateTime(e) => write!(f, "invalid date or time: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DateTime(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<components::Error> for Error {
+    fn from(value: components::Error) -> Self {
+        Error::DateTime(value)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(value: SyntaxError) -> Self {
+        Error::Syntax {
+            position: value.position,
+        }
+    }
+}
+
+/// The version of XML Schema, which decides how years before 1 CE are numbered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Version {
+    /// Year 0000 is not allowed and -0001 is 1 BCE
+    Xsd10,
+    /// Year 0000 is 1 BCE, as in ISO 8601
+    #[default]
+    Xsd11,
+}
+
+/// `xs:dateTime`, like `2023-09-17T09:08:58.5+02:00`
+#[derive(Debug, Clone, Copy)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: Month,
+    pub day: Day,
+    pub hour: Hour,
+    pub minute: Minute,
+    pub second: Second,
+    pub nanosecond: Nanosecond,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:date`, like `2023-09-17` or `2023-09-17Z`
+#[derive(Debug, Clone, Copy)]
+pub struct Date {
+    pub year: i32,
+    pub month: Month,
+    pub day: Day,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:time`, like `09:08:58`
+#[derive(Debug, Clone, Copy)]
+pub struct Time {
+    pub hour: Hour,
+    pub minute: Minute,
+    pub second: Second,
+    pub nanosecond: Nanosecond,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:gYearMonth`, like `2023-09`
+#[derive(Debug, Clone, Copy)]
+pub struct GYearMonth {
+    pub year: i32,
+    pub month: Month,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:gYear`, like `2023` or `-0044`
+#[derive(Debug, Clone, Copy)]
+pub struct GYear {
+    pub year: i32,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:gMonthDay`, a day that recurs every year, like `--09-17`
+#[derive(Debug, Clone, Copy)]
+pub struct GMonthDay {
+    pub month: Month,
+    pub day: Day,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:gDay`, a day that recurs every month, like `---17`
+#[derive(Debug, Clone, Copy)]
+pub struct GDay {
+    pub day: Day,
+    pub timezone: Option<Timeshift>,
+}
+
+/// `xs:gMonth`, a month that recurs every year, like `--09`
+#[derive(Debug, Clone, Copy)]
+pub struct GMonth {
+    pub month: Month,
+    pub timezone: Option<Timeshift>,
+}
+
+/// The seven properties of the XSD date/time model, missing ones left out
+#[derive(Default)]
+struct Properties {
+    year: Option<i32>,
+    month: Option<Month>,
+    day: Option<Day>,
+    /// Nanoseconds since midnight
+    time: Option<i64>,
+    timezone: Option<Timeshift>,
+}
+
+impl Properties {
+    /// Nanoseconds since 1970-01-01T00:00:00Z, or in local time without a
+    /// time zone. Missing properties are filled in like the `timeOnTimeline`
+    /// function of XSD 1.1: year 1972, December, and the last day of the month.
+    fn time_on_timeline(&self) -> i128 {
+        let year = self.year.unwrap_or(1972);
+        let month = self.month.map_or(12, u8::from);
+        let day = self
+            .day
+            .map_or_else(|| calendar::days_in_month(year, month), u8::from);
+        let days = calendar::days_from_civil(year, month, day);
+        let offset = self.timezone.map_or(0, |t| t.seconds_from_east());
+        (days as i128 * 86400 - offset as i128) * 1_000_000_000 + self.time.unwrap_or(0) as i128
+    }
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        const FOURTEEN_HOURS: i128 = 14 * 3600 * 1_000_000_000;
+        let (a, b) = (self.time_on_timeline(), other.time_on_timeline());
+        match (self.timezone.is_some(), other.timezone.is_some()) {
+            (true, false) if a < b - FOURTEEN_HOURS => Some(Ordering::Less),
+            (true, false) if a > b + FOURTEEN_HOURS => Some(Ordering::Greater),
+            (false, true) if a + FOURTEEN_HOURS < b => Some(Ordering::Less),
+            (false, true) if a - FOURTEEN_HOURS > b => Some(Ordering::Greater),
+            (true, false) | (false, true) => None,
+            _ => Some(a.cmp(&b)),
+        }
+    }
+}
+
+fn nanoseconds_of_day(hour: Hour, minute: Minute, second: Second, nanosecond: Nanosecond) -> i64 {
+    let seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+    seconds * 1_000_000_000 + i64::from(u32::from(nanosecond))
+}
+
+impl DateTime {
+    fn properties(&self) -> Properties {
+        Properties {
+            year: Some(self.year),
+            month: Some(self.month),
+            day: Some(self.day),
+            time: Some(nanoseconds_of_day(
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanosecond,
+            )),
+            timezone: self.timezone,
+        }
+    }
+}
+
+impl Date {
+    fn properties(&self) -> Properties {
+        Properties {
+            year: Some(self.year),
+            month: Some(self.month),
+            day: Some(self.day),
+            time: None,
+            timezone: self.timezone,
+        }
+    }
+}
+
+impl Time {
+    fn properties(&self) -> Properties {
+        // XSD 1.1 places times on 1972-12-31
+        Properties {
+            time: Some(nanoseconds_of_day(
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanosecond,
+            )),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+impl GYearMonth {
+    fn properties(&self) -> Properties {
+        Properties {
+            year: Some(self.year),
+            month: Some(self.month),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+impl GYear {
+    fn properties(&self) -> Properties {
+        Properties {
+            year: Some(self.year),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+impl GMonthDay {
+    fn properties(&self) -> Properties {
+        Properties {
+            month: Some(self.month),
+            day: Some(self.day),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+impl GDay {
+    fn properties(&self) -> Properties {
+        Properties {
+            day: Some(self.day),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+impl GMonth {
+    fn properties(&self) -> Properties {
+        Properties {
+            month: Some(self.month),
+            timezone: self.timezone,
+            ..Default::default()
+        }
+    }
+}
+
+/// Reads a value from left to right
+struct Scanner<'a> {
+    scanner: parse_utils::Scanner<'a>,
+    version: Version,
+}
+
+impl<'a> Deref for Scanner<'a> {
+    type Target = parse_utils::Scanner<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.scanner
+    }
+}
+
+impl DerefMut for Scanner<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.scanner
+    }
+}
+
+impl Scanner<'_> {
+    /// At least four digits, without leading zeros when there are more
+    fn year(&mut self) -> Result<i32, Error> {
+        let start = self.position();
+        let negative = self.eat(b'-');
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.len() < 4 || (digits.len() > 4 && digits[0] == b'0') {
+            return Err(Error::Syntax { position: start });
+        }
+        let year: i32 = str::from_utf8(digits)
+            .expect("ASCII digits")
+            .parse()
+            .map_err(|_| components::Error::Range)?;
+        match (self.version, negative, year) {
+            (Version::Xsd10, _, 0) => Err(components::Error::Range.into()),
+            (Version::Xsd10, true, year) => Ok(1 - year),
+            (_, true, year) => Ok(-year),
+            (_, false, year) => Ok(year),
+        }
+    }
+
+    fn month(&mut self) -> Result<Month, Error> {
+        Ok(Month::new(self.number(2)?)?)
+    }
+
+    /// A day, checked against the month when known
+    fn day(&mut self, year: Option<i32>, month: Option<Month>) -> Result<Day, Error> {
+        let day = Day::new(self.number(2)?)?;
+        // Without a year February has 29 days
+        let days_in_month = month.map_or(31, |month| {
+            calendar::days_in_month(year.unwrap_or(2000), month.into())
+        });
+        if u8::from(day) > days_in_month {
+            return Err(components::Error::Range.into());
+        }
+        Ok(day)
+    }
+
+    /// `hh:mm:ss[.s+]`, where `24:00:00` is returned as is
+    fn time(&mut self) -> Result<(Hour, Minute, Second, Nanosecond), Error> {
+        let hour = self.number(2)?;
+        self.expect(b":")?;
+        let minute = self.number(2)?;
+        self.expect(b":")?;
+        let second = self.number(2)?;
+        let mut nanosecond = 0;
+        if self.eat(b'.') {
+            let start = self.position();
+            let digits = self.take_while(|c| c.is_ascii_digit());
+            if digits.is_empty() {
+                return Err(self.error());
+            }
+            let (fraction, rest) = digits.split_at(digits.len().min(9));
+            if rest.iter().any(|&c| c != b'0') {
+                return Err(Error::Syntax {
+                    position: start + 9,
+                });
+            }
+            nanosecond = parse_utils::nanoseconds(fraction);
+        }
+        if (hour == 24 && (minute, second, nanosecond) != (0, 0, 0))
+            || hour > 24
+            || minute > 59
+            || second > 59
+        {
+            return Err(components::Error::Range.into());
+        }
+        Ok((
+            Hour::new(hour)?,
+            Minute::new(minute)?,
+            Second::new(second)?,
+            Nanosecond::new(nanosecond)?,
+        ))
+    }
+
+    /// An optional `Z` or `±hh:mm` up to 14 hours, after which the value must end
+    fn timezone(&mut self) -> Result<Option<Timeshift>, Error> {
+        let start = self.position();
+        let timezone = match self.peek() {
+            None => return Ok(None),
+            Some(b'Z') => {
+                self.advance(1);
+                Timeshift::Utc
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                self.advance(1);
+                let hours = self.number(2)?;
+                self.expect(b":")?;
+                let minutes = self.number(2)?;
+                if hours > 14 || minutes > 59 || (hours == 14 && minutes != 0) {
+                    return Err(Error::Syntax { position: start });
+                }
+                Timeshift::offset(sign == b'+', Hour::new(hours)?, Minute::new(minutes)?)
+            }
+            Some(_) => return Err(self.error()),
+        };
+        match self.peek() {
+            None => Ok(Some(timezone)),
+            Some(_) => Err(self.error()),
+        }
+    }
+}
+
+impl DateTime {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        let year = scanner.year()?;
+        scanner.expect(b"-")?;
+        let month = scanner.month()?;
+        scanner.expect(b"-")?;
+        let day = scanner.day(Some(year), Some(month))?;
+        scanner.expect(b"T")?;
+        let (hour, minute, second, nanosecond) = scanner.time()?;
+        let timezone = scanner.timezone()?;
+        let (year, month, day, hour) = end_of_day(year, month, day, hour)?;
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            timezone,
+        })
+    }
+}
+
+impl Date {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        let year = scanner.year()?;
+        scanner.expect(b"-")?;
+        let month = scanner.month()?;
+        scanner.expect(b"-")?;
+        let day = scanner.day(Some(year), Some(month))?;
+        Ok(Self {
+            year,
+            month,
+            day,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl Time {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        let (hour, minute, second, nanosecond) = scanner.time()?;
+        let hour = match u8::from(hour) {
+            24 => Hour::new(0)?,
+            _ => hour,
+        };
+        Ok(Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl GYearMonth {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        let year = scanner.year()?;
+        scanner.expect(b"-")?;
+        Ok(Self {
+            year,
+            month: scanner.month()?,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl GYear {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        Ok(Self {
+            year: scanner.year()?,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl GMonthDay {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        scanner.expect(b"--")?;
+        let month = scanner.month()?;
+        scanner.expect(b"-")?;
+        Ok(Self {
+            month,
+            day: scanner.day(None, Some(month))?,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl GDay {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        scanner.expect(b"---")?;
+        Ok(Self {
+            day: scanner.day(None, None)?,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+impl GMonth {
+    fn scan(scanner: &mut Scanner) -> Result<Self, Error> {
+        scanner.expect(b"--")?;
+        Ok(Self {
+            month: scanner.month()?,
+            timezone: scanner.timezone()?,
+        })
+    }
+}
+
+/// A year with at least four digits, numbered as in the version
+struct XsdYear(i32, Version);
+
+impl fmt::Display for XsdYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // XSD 1.0 has no year 0, so 1 BCE is -0001
+        let year = match self.1 {
+            Version::Xsd10 if self.0 <= 0 => i64::from(self.0) - 1,
+            _ => i64::from(self.0),
+        };
+        if year < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{:04}", year.unsigned_abs())
+    }
+}
+
+/// A value written with years numbered as in the version
+struct Versioned<'a, T>(&'a T, Version);
+
+/// Seconds with the fraction, without trailing zeros
+struct XsdSecond(Second, Nanosecond);
+
+impl fmt::Display for XsdSecond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        let nanosecond = u32::from(self.1);
+        if nanosecond != 0 {
+            let fraction = format!("{:09}", nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+struct XsdTimezone(Option<Timeshift>);
+
+impl fmt::Display for XsdTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(timezone) => write!(f, "{}", timezone),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Versioned<'_, DateTime> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}T{}:{}:{}{}",
+            XsdYear(self.0.year, self.1),
+            self.0.month,
+            self.0.day,
+            self.0.hour,
+            self.0.minute,
+            XsdSecond(self.0.second, self.0.nanosecond),
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, Date> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}{}",
+            XsdYear(self.0.year, self.1),
+            self.0.month,
+            self.0.day,
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, Time> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}{}",
+            self.0.hour,
+            self.0.minute,
+            XsdSecond(self.0.second, self.0.nanosecond),
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, GYearMonth> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}{}",
+            XsdYear(self.0.year, self.1),
+            self.0.month,
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, GYear> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            XsdYear(self.0.year, self.1),
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, GMonthDay> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "--{}-{}{}",
+            self.0.month,
+            self.0.day,
+            XsdTimezone(self.0.timezone)
+        )
+    }
+}
+
+impl fmt::Display for Versioned<'_, GDay> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "---{}{}", self.0.day, XsdTimezone(self.0.timezone))
+    }
+}
+
+impl fmt::Display for Versioned<'_, GMonth> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "--{}{}", self.0.month, XsdTimezone(self.0.timezone))
+    }
+}
+
+macro_rules! impl_xsd {
+    ($type:ty) => {
+        impl $type {
+            /// Parse the lexical form, numbering years before 1 CE as in `version`
+            pub fn parse_version(s: &str, version: Version) -> Result<Self, Error> {
+                let mut scanner = Scanner {
+                    scanner: parse_utils::Scanner::new(s.as_bytes()),
+                    version,
+                };
+                Self::scan(&mut scanner)
+            }
+
+            /// Write the lexical form, numbering years before 1 CE as in `version`
+            pub fn display_version(&self, version: Version) -> impl fmt::Display + '_ {
+                Versioned(self, version)
+            }
+        }
+
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Versioned(self, Version::Xsd11).fmt(f)
+            }
+        }
+
+        impl str::FromStr for $type {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse_version(s, Version::Xsd11)
+            }
+        }
+
+        impl PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd for $type {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.properties().partial_cmp(&other.properties())
+            }
+        }
+    };
+}
+
+impl_xsd!(DateTime);
+impl_xsd!(Date);
+impl_xsd!(Time);
+impl_xsd!(GYearMonth);
+impl_xsd!(GYear);
+impl_xsd!(GMonthDay);
+impl_xsd!(GDay);
+impl_xsd!(GMonth);
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::{Date, DateTime, Error, GDay, GMonth, GMonthDay, GYear, GYearMonth, Time, Version};
+
+    fn round_trip<T>(s: &str, expected: &str)
+    where
+        T: core::str::FromStr<Err = Error> + core::fmt::Display,
+    {
+        let value = s.parse::<T>();
+        assert!(value.is_ok(), "{}: {:?}", s, value.err());
+        assert_eq!(value.unwrap().to_string(), expected, "{}", s);
+    }
+
+    #[test]
+    fn test_parse() {
+        round_trip::<DateTime>("2023-09-17T09:08:58", "2023-09-17T09:08:58");
+        round_trip::<DateTime>("2023-09-17T09:08:58.500Z", "2023-09-17T09:08:58.5Z");
+        round_trip::<DateTime>("2023-09-17T09:08:58-14:00", "2023-09-17T09:08:58-14:00");
+        round_trip::<DateTime>("2023-12-31T24:00:00+01:00", "2024-01-01T00:00:00+01:00");
+        round_trip::<DateTime>("-0044-03-15T12:00:00", "-0044-03-15T12:00:00");
+        round_trip::<DateTime>("12023-09-17T09:08:58", "12023-09-17T09:08:58");
+        round_trip::<DateTime>(
+            "2023-09-17T09:08:58.1234567890000Z",
+            "2023-09-17T09:08:58.123456789Z",
+        );
+        round_trip::<Date>("2023-09-17", "2023-09-17");
+        round_trip::<Date>("2024-02-29Z", "2024-02-29Z");
+        round_trip::<Date>("0000-01-01", "0000-01-01");
+        round_trip::<Time>("09:08:58", "09:08:58");
+        round_trip::<Time>("24:00:00+02:00", "00:00:00+02:00");
+        round_trip::<GYearMonth>("2023-09", "2023-09");
+        round_trip::<GYearMonth>("2023-09-05:00", "2023-09-05:00");
+        round_trip::<GYear>("2023", "2023");
+        round_trip::<GYear>("-0001Z", "-0001Z");
+        round_trip::<GMonthDay>("--02-29", "--02-29");
+        round_trip::<GMonthDay>("--09-17+02:00", "--09-17+02:00");
+        round_trip::<GDay>("---31", "---31");
+        round_trip::<GMonth>("--09Z", "--09Z");
+
+        let date = Date::parse_version("-0001-01-01", Version::Xsd10).unwrap();
+        assert_eq!(date.year, 0);
+        let date = Date::parse_version("0001-01-01", Version::Xsd10).unwrap();
+        assert_eq!(date.year, 1);
+        assert!(Date::parse_version("0000-01-01", Version::Xsd10).is_err());
+
+        for s in ["-0001-01-01", "-0002-12-31Z", "0001-01-01"] {
+            let date = Date::parse_version(s, Version::Xsd10).unwrap();
+            assert_eq!(date.display_version(Version::Xsd10).to_string(), s);
+        }
+        let date = Date::parse_version("-0001-01-01", Version::Xsd10).unwrap();
+        assert_eq!(date.to_string(), "0000-01-01");
+        let date_time = DateTime::parse_version("-0001-01-01T00:00:00", Version::Xsd10).unwrap();
+        assert_eq!(
+            date_time.display_version(Version::Xsd10).to_string(),
+            "-0001-01-01T00:00:00"
+        );
+        let year = GYear::parse_version("-0001", Version::Xsd11).unwrap();
+        assert_eq!(year.display_version(Version::Xsd10).to_string(), "-0002");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for s in [
+            "2023-09-17",
+            "2023-09-17T09:08",
+            "2023-09-17 09:08:58",
+            "2023-09-17t09:08:58",
+            "2023-09-17T09:08:58z",
+            "2023-09-17T09:08:58.",
+            "2023-09-17T09:08:58+14:30",
+            "2023-09-17T09:08:58+0200",
+            "2023-09-17T09:08:58.1234567891",
+            "2023-02-29T09:08:58",
+            "2023-09-17T24:00:01",
+            "2023-09-17T09:60:00",
+            "2023-09-17T09:08:60",
+            "023-09-17T09:08:58",
+            "02023-09-17T09:08:58",
+            "+2023-09-17T09:08:58",
+        ] {
+            assert!(s.parse::<DateTime>().is_err(), "{}", s);
+        }
+        for s in ["--02-30", "--13-01", "-02-28", "--02-28-"] {
+            assert!(s.parse::<GMonthDay>().is_err(), "{}", s);
+        }
+        for s in ["---32", "--31", "---1"] {
+            assert!(s.parse::<GDay>().is_err(), "{}", s);
+        }
+        for s in ["--09--", "--9", "2023-09"] {
+            assert!(s.parse::<GMonth>().is_err(), "{}", s);
+        }
+        assert!(matches!(
+            "2023-09-17T09:08:58x".parse::<DateTime>(),
+            Err(Error::Syntax { position: 19 })
+        ));
+    }
+
+    #[test]
+    fn test_order() {
+        let date_time = |s: &str| s.parse::<DateTime>().unwrap();
+        assert_eq!(
+            date_time("2023-09-17T09:08:58Z"),
+            date_time("2023-09-17T11:08:58+02:00")
+        );
+        assert!(date_time("2023-09-17T09:08:58Z") < date_time("2023-09-17T09:08:59Z"));
+        assert!(date_time("2023-09-17T09:08:58") < date_time("2023-09-17T09:08:59"));
+        // Without a time zone, a value could be 14 hours earlier or later
+        for (a, b, ordering) in [
+            (
+                "2000-01-15T12:00:00",
+                "2000-01-16T12:00:00Z",
+                Some(Ordering::Less),
+            ),
+            ("2000-01-01T12:00:00", "1999-12-31T23:00:00Z", None),
+            ("2000-01-01T12:00:00", "2000-01-01T12:00:00Z", None),
+            ("2000-01-16T12:00:00", "2000-01-16T12:00:00Z", None),
+            (
+                "2000-01-16T00:00:00",
+                "2000-01-16T14:00:01Z",
+                Some(Ordering::Less),
+            ),
+            ("2000-01-16T00:00:00", "2000-01-16T14:00:00Z", None),
+            (
+                "2000-01-17T00:00:00",
+                "2000-01-16T09:59:59Z",
+                Some(Ordering::Greater),
+            ),
+        ] {
+            assert_eq!(
+                date_time(a).partial_cmp(&date_time(b)),
+                ordering,
+                "{} {}",
+                a,
+                b
+            );
+            let reversed = ordering.map(Ordering::reverse);
+            assert_eq!(
+                date_time(b).partial_cmp(&date_time(a)),
+                reversed,
+                "{} {}",
+                b,
+                a
+            );
+        }
+        assert_ne!(
+            date_time("2023-09-17T09:08:58"),
+            date_time("2023-09-17T09:08:58Z")
+        );
+
+        let time = |s: &str| s.parse::<Time>().unwrap();
+        assert_eq!(time("24:00:00"), time("00:00:00"));
+        assert_eq!(time("11:00:00-01:00"), time("12:00:00Z"));
+        let date = |s: &str| s.parse::<Date>().unwrap();
+        assert!(date("2023-09-17+02:00") < date("2023-09-17Z"));
+        assert!(date("-0001-01-01") < date("0000-01-01"));
+        let month_day = |s: &str| s.parse::<GMonthDay>().unwrap();
+        assert!(month_day("--02-28") < month_day("--02-29"));
+        assert!(month_day("--02-29") < month_day("--03-01"));
+        let year = |s: &str| s.parse::<GYear>().unwrap();
+        assert!(year("1999") < year("2000"));
+        assert_eq!(year("2000Z"), year("2000-00:00"));
+    }
+}